@@ -123,11 +123,15 @@ impl AudioSubsystem {
         AudioQueue::open_queue(self, device, spec)
     }
 
+    /// Returns the name of the audio driver in use, such as `"pulseaudio"` or `"pipewire"`, or
+    /// `"unknown"` if SDL doesn't report one.
     #[doc(alias = "SDL_GetCurrentAudioDriver")]
     pub fn current_audio_driver(&self) -> &'static str {
         unsafe {
             let buf = sys::SDL_GetCurrentAudioDriver();
-            assert!(!buf.is_null());
+            if buf.is_null() {
+                return "unknown";
+            }
 
             CStr::from_ptr(buf as *const _).to_str().unwrap()
         }
@@ -272,6 +276,15 @@ impl AudioFormat {
     fn to_ll(self) -> sys::SDL_AudioFormat {
         self as sys::SDL_AudioFormat
     }
+
+    /// The size of a sample in bytes.
+    fn size(self) -> usize {
+        (self.to_ll() as u32 & sys::SDL_AUDIO_MASK_BITSIZE) as usize / 8
+    }
+
+    fn is_big_endian(self) -> bool {
+        self.to_ll() as u32 & sys::SDL_AUDIO_MASK_ENDIAN != 0
+    }
 }
 
 #[cfg(target_endian = "little")]
@@ -507,6 +520,90 @@ impl Drop for AudioSpecWAV {
     }
 }
 
+/// Saves PCM data to a WAVE file at the given path.
+///
+/// `data` must be in the format described by `spec`. See `save_wav_rw`.
+pub fn save_wav<P: AsRef<Path>>(path: P, spec: &AudioSpec, data: &[u8]) -> Result<(), String> {
+    let mut file = RWops::from_file(path, "wb")?;
+    save_wav_rw(&mut file, spec, data)
+}
+
+/// Writes PCM data as a WAVE file to the data destination.
+///
+/// `data` must be in the format described by `spec`. WAVE files store samples in little-endian
+/// order, so big-endian samples are byte-swapped as they are written. The `S8`, `U16LSB` and
+/// `U16MSB` formats can't be stored in a WAVE file and return an error.
+pub fn save_wav_rw(dst: &mut RWops, spec: &AudioSpec, data: &[u8]) -> Result<(), String> {
+    use std::io::Write;
+
+    let header = wav_header(spec.format, spec.channels, spec.freq, data.len())?;
+    dst.write_all(&header).map_err(|e| e.to_string())?;
+
+    let sample_size = spec.format.size();
+    if spec.format.is_big_endian() && sample_size > 1 {
+        let mut swapped = data.to_vec();
+        for sample in swapped.chunks_exact_mut(sample_size) {
+            sample.reverse();
+        }
+        dst.write_all(&swapped).map_err(|e| e.to_string())
+    } else {
+        dst.write_all(data).map_err(|e| e.to_string())
+    }
+}
+
+/// Builds the RIFF/WAVE header for `data_len` bytes of PCM data.
+fn wav_header(
+    format: AudioFormat,
+    channels: u8,
+    freq: i32,
+    data_len: usize,
+) -> Result<[u8; 44], String> {
+    const WAVE_FORMAT_PCM: u16 = 1;
+    const WAVE_FORMAT_IEEE_FLOAT: u16 = 3;
+
+    let format_tag = match format {
+        AudioFormat::U8
+        | AudioFormat::S16LSB
+        | AudioFormat::S16MSB
+        | AudioFormat::S32LSB
+        | AudioFormat::S32MSB => WAVE_FORMAT_PCM,
+        AudioFormat::F32LSB | AudioFormat::F32MSB => WAVE_FORMAT_IEEE_FLOAT,
+        AudioFormat::S8 | AudioFormat::U16LSB | AudioFormat::U16MSB => {
+            return Err(format!(
+                "{:?} samples can't be stored in a WAVE file",
+                format
+            ));
+        }
+    };
+    if freq <= 0 || channels == 0 {
+        return Err("Invalid audio spec".to_owned());
+    }
+
+    let sample_size = format.size() as u32;
+    let block_align = channels as u32 * sample_size;
+    let byte_rate = freq as u32 * block_align;
+    let data_len = u32::try_from(data_len)
+        .ok()
+        .filter(|&len| len <= u32::MAX - 36)
+        .ok_or_else(|| "Audio data is too large for a WAVE file".to_owned())?;
+
+    let mut header = [0; 44];
+    header[0..4].copy_from_slice(b"RIFF");
+    header[4..8].copy_from_slice(&(36 + data_len).to_le_bytes());
+    header[8..12].copy_from_slice(b"WAVE");
+    header[12..16].copy_from_slice(b"fmt ");
+    header[16..20].copy_from_slice(&16u32.to_le_bytes());
+    header[20..22].copy_from_slice(&format_tag.to_le_bytes());
+    header[22..24].copy_from_slice(&(channels as u16).to_le_bytes());
+    header[24..28].copy_from_slice(&(freq as u32).to_le_bytes());
+    header[28..32].copy_from_slice(&byte_rate.to_le_bytes());
+    header[32..34].copy_from_slice(&(block_align as u16).to_le_bytes());
+    header[34..36].copy_from_slice(&((sample_size * 8) as u16).to_le_bytes());
+    header[36..40].copy_from_slice(b"data");
+    header[40..44].copy_from_slice(&data_len.to_le_bytes());
+    Ok(header)
+}
+
 pub trait AudioCallback: Send
 where
     Self::Channel: AudioFormatNum + 'static,
@@ -727,6 +824,37 @@ impl AudioSpec {
     }
 }
 
+/// The fields of an obtained [`AudioSpec`] that don't match what was requested with an
+/// [`AudioSpecDesired`].
+///
+/// Fields that were left as `None` in the desired spec are never reported, since any value the
+/// device picks for them is acceptable. The sample format is always compared against the
+/// device's channel type.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct AudioSpecDiff {
+    pub freq: bool,
+    pub format: bool,
+    pub channels: bool,
+    pub samples: bool,
+}
+
+impl AudioSpecDiff {
+    /// Compares the `desired` spec and sample `format` with the `obtained` spec.
+    pub fn new(desired: &AudioSpecDesired, format: AudioFormat, obtained: &AudioSpec) -> Self {
+        AudioSpecDiff {
+            freq: matches!(desired.freq, Some(freq) if freq != obtained.freq),
+            format: format != obtained.format,
+            channels: matches!(desired.channels, Some(channels) if channels != obtained.channels),
+            samples: matches!(desired.samples, Some(samples) if samples != obtained.samples),
+        }
+    }
+
+    /// Returns `true` if any field differs from the desired spec.
+    pub fn any(&self) -> bool {
+        self.freq || self.format || self.channels || self.samples
+    }
+}
+
 enum AudioDeviceID {
     PlaybackDevice(sys::SDL_AudioDeviceID),
 }
@@ -753,6 +881,7 @@ pub struct AudioQueue<Channel: AudioFormatNum> {
     device_id: AudioDeviceID,
     phantom: PhantomData<Channel>,
     spec: AudioSpec,
+    spec_diff: AudioSpecDiff,
 }
 
 impl<'a, Channel: AudioFormatNum> AudioQueue<Channel> {
@@ -793,13 +922,15 @@ impl<'a, Channel: AudioFormatNum> AudioQueue<Channel> {
                 id => {
                     let obtained = obtained.assume_init();
                     let device_id = AudioDeviceID::PlaybackDevice(id);
-                    let spec = AudioSpec::convert_from_ll(obtained);
+                    let obtained = AudioSpec::convert_from_ll(obtained);
+                    let spec_diff = AudioSpecDiff::new(spec, Channel::audio_format(), &obtained);
 
                     Ok(AudioQueue {
                         subsystem: a.clone(),
                         device_id,
                         phantom: PhantomData,
-                        spec,
+                        spec: obtained,
+                        spec_diff,
                     })
                 }
             }
@@ -817,6 +948,13 @@ impl<'a, Channel: AudioFormatNum> AudioQueue<Channel> {
         &self.spec
     }
 
+    /// Returns which fields of the obtained [`spec`](Self::spec) differ from the desired spec
+    /// the device was opened with.
+    #[inline]
+    pub fn spec_diff(&self) -> AudioSpecDiff {
+        self.spec_diff
+    }
+
     pub fn status(&self) -> AudioStatus {
         unsafe {
             let status = sys::SDL_GetAudioDeviceStatus(self.device_id.id());
@@ -889,6 +1027,7 @@ pub struct AudioDevice<CB: AudioCallback> {
     subsystem: AudioSubsystem,
     device_id: AudioDeviceID,
     spec: AudioSpec,
+    spec_diff: AudioSpecDiff,
     /// Store the callback to keep it alive for the entire duration of `AudioDevice`.
     userdata: Box<Option<CB>>,
 }
@@ -934,15 +1073,21 @@ impl<CB: AudioCallback> AudioDevice<CB> {
                 id => {
                     let obtained = obtained.assume_init();
                     let device_id = AudioDeviceID::PlaybackDevice(id);
-                    let spec = AudioSpec::convert_from_ll(obtained);
+                    let obtained = AudioSpec::convert_from_ll(obtained);
+                    let spec_diff = AudioSpecDiff::new(
+                        spec,
+                        <CB::Channel as AudioFormatNum>::audio_format(),
+                        &obtained,
+                    );
 
-                    *userdata = Some(get_callback(spec));
+                    *userdata = Some(get_callback(obtained));
 
                     Ok(AudioDevice {
                         subsystem: a.clone(),
                         device_id,
                         userdata,
-                        spec,
+                        spec: obtained,
+                        spec_diff,
                     })
                 }
             }
@@ -995,6 +1140,13 @@ impl<CB: AudioCallback> AudioDevice<CB> {
         &self.spec
     }
 
+    /// Returns which fields of the obtained [`spec`](Self::spec) differ from the desired spec
+    /// the device was opened with.
+    #[inline]
+    pub fn spec_diff(&self) -> AudioSpecDiff {
+        self.spec_diff
+    }
+
     pub fn status(&self) -> AudioStatus {
         unsafe {
             let status = sys::SDL_GetAudioDeviceStatus(self.device_id.id());
@@ -1170,9 +1322,113 @@ impl AudioCVT {
     }
 }
 
+/// A stream converting audio data between formats, channel counts and sample rates.
+///
+/// Unlike `AudioCVT`, which converts a complete buffer at once, an `AudioStream` buffers its
+/// input internally, so data can be put and retrieved in chunks of arbitrary sizes.
+pub struct AudioStream {
+    raw: *mut sys::SDL_AudioStream,
+}
+
+impl AudioStream {
+    #[doc(alias = "SDL_NewAudioStream")]
+    pub fn new(
+        src_format: AudioFormat,
+        src_channels: u8,
+        src_rate: i32,
+        dst_format: AudioFormat,
+        dst_channels: u8,
+        dst_rate: i32,
+    ) -> Result<AudioStream, String> {
+        let raw = unsafe {
+            sys::SDL_NewAudioStream(
+                src_format.to_ll(),
+                src_channels,
+                src_rate as c_int,
+                dst_format.to_ll(),
+                dst_channels,
+                dst_rate as c_int,
+            )
+        };
+        if raw.is_null() {
+            Err(get_error())
+        } else {
+            Ok(AudioStream { raw })
+        }
+    }
+
+    /// Adds data in the source format to the stream.
+    #[doc(alias = "SDL_AudioStreamPut")]
+    pub fn put(&mut self, buf: &[u8]) -> Result<(), String> {
+        let len = c_int::try_from(buf.len()).map_err(|_| "Buffer length overflow".to_owned())?;
+        let ret = unsafe { sys::SDL_AudioStreamPut(self.raw, buf.as_ptr() as *const c_void, len) };
+        if ret == 0 {
+            Ok(())
+        } else {
+            Err(get_error())
+        }
+    }
+
+    /// Reads converted data from the stream into `buf`, returning the number of bytes read.
+    ///
+    /// This may read less than `buf.len()` bytes, depending on how much converted data is
+    /// available.
+    #[doc(alias = "SDL_AudioStreamGet")]
+    pub fn get(&mut self, buf: &mut [u8]) -> Result<usize, String> {
+        let len = c_int::try_from(buf.len()).unwrap_or(c_int::MAX);
+        let ret =
+            unsafe { sys::SDL_AudioStreamGet(self.raw, buf.as_mut_ptr() as *mut c_void, len) };
+        if ret < 0 {
+            Err(get_error())
+        } else {
+            Ok(ret as usize)
+        }
+    }
+
+    /// Gets the number of converted bytes available to be read with `get`.
+    #[doc(alias = "SDL_AudioStreamAvailable")]
+    pub fn available(&self) -> usize {
+        unsafe { sys::SDL_AudioStreamAvailable(self.raw) as usize }
+    }
+
+    /// Converts any data buffered in the stream so it becomes available immediately.
+    ///
+    /// This is intended to signal the end of the input; more data can still be put afterwards,
+    /// but there will be gaps in the output.
+    #[doc(alias = "SDL_AudioStreamFlush")]
+    pub fn flush(&mut self) -> Result<(), String> {
+        let ret = unsafe { sys::SDL_AudioStreamFlush(self.raw) };
+        if ret == 0 {
+            Ok(())
+        } else {
+            Err(get_error())
+        }
+    }
+
+    /// Discards any pending data in the stream without converting it.
+    #[doc(alias = "SDL_AudioStreamClear")]
+    pub fn clear(&mut self) {
+        unsafe { sys::SDL_AudioStreamClear(self.raw) }
+    }
+
+    #[inline]
+    pub fn raw(&self) -> *mut sys::SDL_AudioStream {
+        self.raw
+    }
+}
+
+impl Drop for AudioStream {
+    #[doc(alias = "SDL_FreeAudioStream")]
+    fn drop(&mut self) {
+        unsafe { sys::SDL_FreeAudioStream(self.raw) }
+    }
+}
+
 #[cfg(test)]
 mod test {
-    use super::{AudioCVT, AudioFormat};
+    use super::{
+        wav_header, AudioCVT, AudioFormat, AudioSpec, AudioSpecDesired, AudioSpecDiff, AudioStream,
+    };
 
     #[test]
     fn test_audio_cvt() {
@@ -1204,4 +1460,80 @@ mod test {
         // // to maybe re-enable it someday
         // assert_eq!(new_buffer, new_buffer_expected);
     }
+
+    #[test]
+    fn test_audio_stream() {
+        // 0,1,2,3, ...
+        let buffer: Vec<u8> = (0..255).collect();
+
+        let mut stream =
+            AudioStream::new(AudioFormat::U8, 1, 44100, AudioFormat::U8, 2, 44100).unwrap();
+        stream.put(&buffer).unwrap();
+        stream.flush().unwrap();
+
+        // going from mono to stereo doubles the amount of data
+        let available = stream.available();
+        assert_eq!(available, buffer.len() * 2);
+
+        let mut out = vec![0; available + 16];
+        assert_eq!(stream.get(&mut out).unwrap(), available);
+        assert_eq!(stream.available(), 0);
+    }
+
+    #[test]
+    fn test_wav_header() {
+        let header = wav_header(AudioFormat::S16MSB, 2, 44100, 1000).unwrap();
+        assert_eq!(&header[0..4], b"RIFF");
+        assert_eq!(header[4..8], 1036u32.to_le_bytes());
+        assert_eq!(&header[8..16], b"WAVEfmt ");
+        // PCM, 2 channels, 44100 Hz
+        assert_eq!(header[20..22], 1u16.to_le_bytes());
+        assert_eq!(header[22..24], 2u16.to_le_bytes());
+        assert_eq!(header[24..28], 44100u32.to_le_bytes());
+        // byte rate, block align and bits per sample
+        assert_eq!(header[28..32], (44100u32 * 4).to_le_bytes());
+        assert_eq!(header[32..34], 4u16.to_le_bytes());
+        assert_eq!(header[34..36], 16u16.to_le_bytes());
+        assert_eq!(&header[36..40], b"data");
+        assert_eq!(header[40..44], 1000u32.to_le_bytes());
+
+        let header = wav_header(AudioFormat::F32LSB, 1, 48000, 0).unwrap();
+        assert_eq!(header[20..22], 3u16.to_le_bytes());
+        assert_eq!(header[34..36], 32u16.to_le_bytes());
+
+        assert!(wav_header(AudioFormat::S8, 1, 44100, 0).is_err());
+    }
+
+    #[test]
+    fn test_audio_spec_diff() {
+        let desired = AudioSpecDesired {
+            freq: Some(44100),
+            channels: Some(2),
+            samples: None,
+        };
+        let obtained = AudioSpec {
+            freq: 48000,
+            format: AudioFormat::F32LSB,
+            channels: 2,
+            silence: 0,
+            samples: 512,
+            size: 4096,
+        };
+
+        let diff = AudioSpecDiff::new(&desired, AudioFormat::F32LSB, &obtained);
+        assert!(diff.any());
+        assert!(diff.freq);
+        assert!(!diff.format && !diff.channels && !diff.samples);
+
+        let diff = AudioSpecDiff::new(&desired, AudioFormat::S16LSB, &obtained);
+        assert!(diff.format);
+
+        let desired = AudioSpecDesired {
+            freq: None,
+            ..desired
+        };
+        let diff = AudioSpecDiff::new(&desired, AudioFormat::F32LSB, &obtained);
+        assert_eq!(diff, AudioSpecDiff::default());
+        assert!(!diff.any());
+    }
 }
@@ -13,6 +13,7 @@ use std::convert::TryInto;
 
 use crate::common::{validate_int, IntegerOrSdlError};
 use crate::get_error;
+use crate::haptic::Haptic;
 use crate::joystick;
 use crate::GameControllerSubsystem;
 use std::mem::transmute;
@@ -114,6 +115,10 @@ impl GameControllerSubsystem {
 
     /// If state is `true` controller events are processed, otherwise
     /// they're ignored.
+    ///
+    /// Disabling controller events is useful when polling the controller state
+    /// manually, e.g. with `GameController::axis` or `GameController::button`. In
+    /// that case, call `update` before reading the state to get up to date values.
     #[doc(alias = "SDL_GameControllerEventState")]
     pub fn set_event_state(&self, state: bool) {
         unsafe { sys::SDL_GameControllerEventState(state as i32) };
@@ -376,6 +381,94 @@ impl Button {
     }
 }
 
+/// Return the canonical SDL name of `button`, as used by the game controller mapping strings.
+///
+/// Unlike `Button::string`, this does not allocate.
+#[doc(alias = "SDL_GameControllerGetStringForButton")]
+pub fn button_name(button: Button) -> &'static str {
+    c_str_to_static_str(unsafe { sys::SDL_GameControllerGetStringForButton(button.to_ll()) })
+}
+
+/// Return the canonical SDL name of `axis`, as used by the game controller mapping strings.
+///
+/// Unlike `Axis::string`, this does not allocate.
+#[doc(alias = "SDL_GameControllerGetStringForAxis")]
+pub fn axis_name(axis: Axis) -> &'static str {
+    c_str_to_static_str(unsafe { sys::SDL_GameControllerGetStringForAxis(axis.to_ll()) })
+}
+
+/// Return the button named `name`, the reverse of `button_name`, or `None` if the name is not
+/// recognized.
+pub fn button_from_string(name: &str) -> Option<Button> {
+    Button::from_string(name)
+}
+
+/// Return the axis named `name`, the reverse of `axis_name`, or `None` if the name is not
+/// recognized.
+pub fn axis_from_string(name: &str) -> Option<Axis> {
+    Axis::from_string(name)
+}
+
+/// The family of a game controller, e.g. to show the matching button glyphs.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[repr(u32)]
+pub enum ControllerType {
+    Unknown = sys::SDL_GameControllerType::SDL_CONTROLLER_TYPE_UNKNOWN as u32,
+    Xbox360 = sys::SDL_GameControllerType::SDL_CONTROLLER_TYPE_XBOX360 as u32,
+    XboxOne = sys::SDL_GameControllerType::SDL_CONTROLLER_TYPE_XBOXONE as u32,
+    PS3 = sys::SDL_GameControllerType::SDL_CONTROLLER_TYPE_PS3 as u32,
+    PS4 = sys::SDL_GameControllerType::SDL_CONTROLLER_TYPE_PS4 as u32,
+    NintendoSwitchPro = sys::SDL_GameControllerType::SDL_CONTROLLER_TYPE_NINTENDO_SWITCH_PRO as u32,
+    Virtual = sys::SDL_GameControllerType::SDL_CONTROLLER_TYPE_VIRTUAL as u32,
+    PS5 = sys::SDL_GameControllerType::SDL_CONTROLLER_TYPE_PS5 as u32,
+    AmazonLuna = sys::SDL_GameControllerType::SDL_CONTROLLER_TYPE_AMAZON_LUNA as u32,
+    GoogleStadia = sys::SDL_GameControllerType::SDL_CONTROLLER_TYPE_GOOGLE_STADIA as u32,
+    NvidiaShield = sys::SDL_GameControllerType::SDL_CONTROLLER_TYPE_NVIDIA_SHIELD as u32,
+    NintendoSwitchJoyConLeft =
+        sys::SDL_GameControllerType::SDL_CONTROLLER_TYPE_NINTENDO_SWITCH_JOYCON_LEFT as u32,
+    NintendoSwitchJoyConRight =
+        sys::SDL_GameControllerType::SDL_CONTROLLER_TYPE_NINTENDO_SWITCH_JOYCON_RIGHT as u32,
+    NintendoSwitchJoyConPair =
+        sys::SDL_GameControllerType::SDL_CONTROLLER_TYPE_NINTENDO_SWITCH_JOYCON_PAIR as u32,
+}
+
+impl ControllerType {
+    pub fn from_ll(controller_type: sys::SDL_GameControllerType) -> ControllerType {
+        #[allow(unreachable_patterns)]
+        match controller_type {
+            sys::SDL_GameControllerType::SDL_CONTROLLER_TYPE_UNKNOWN => ControllerType::Unknown,
+            sys::SDL_GameControllerType::SDL_CONTROLLER_TYPE_XBOX360 => ControllerType::Xbox360,
+            sys::SDL_GameControllerType::SDL_CONTROLLER_TYPE_XBOXONE => ControllerType::XboxOne,
+            sys::SDL_GameControllerType::SDL_CONTROLLER_TYPE_PS3 => ControllerType::PS3,
+            sys::SDL_GameControllerType::SDL_CONTROLLER_TYPE_PS4 => ControllerType::PS4,
+            sys::SDL_GameControllerType::SDL_CONTROLLER_TYPE_NINTENDO_SWITCH_PRO => {
+                ControllerType::NintendoSwitchPro
+            }
+            sys::SDL_GameControllerType::SDL_CONTROLLER_TYPE_VIRTUAL => ControllerType::Virtual,
+            sys::SDL_GameControllerType::SDL_CONTROLLER_TYPE_PS5 => ControllerType::PS5,
+            sys::SDL_GameControllerType::SDL_CONTROLLER_TYPE_AMAZON_LUNA => {
+                ControllerType::AmazonLuna
+            }
+            sys::SDL_GameControllerType::SDL_CONTROLLER_TYPE_GOOGLE_STADIA => {
+                ControllerType::GoogleStadia
+            }
+            sys::SDL_GameControllerType::SDL_CONTROLLER_TYPE_NVIDIA_SHIELD => {
+                ControllerType::NvidiaShield
+            }
+            sys::SDL_GameControllerType::SDL_CONTROLLER_TYPE_NINTENDO_SWITCH_JOYCON_LEFT => {
+                ControllerType::NintendoSwitchJoyConLeft
+            }
+            sys::SDL_GameControllerType::SDL_CONTROLLER_TYPE_NINTENDO_SWITCH_JOYCON_RIGHT => {
+                ControllerType::NintendoSwitchJoyConRight
+            }
+            sys::SDL_GameControllerType::SDL_CONTROLLER_TYPE_NINTENDO_SWITCH_JOYCON_PAIR => {
+                ControllerType::NintendoSwitchJoyConPair
+            }
+            _ => ControllerType::Unknown,
+        }
+    }
+}
+
 /// Possible return values for `add_mapping`
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
 pub enum MappingStatus {
@@ -404,6 +497,12 @@ impl GameController {
         c_str_to_string(name)
     }
 
+    /// Return the family of the controller, or `ControllerType::Unknown` if SDL can't tell.
+    #[doc(alias = "SDL_GameControllerGetType")]
+    pub fn controller_type(&self) -> ControllerType {
+        ControllerType::from_ll(unsafe { sys::SDL_GameControllerGetType(self.raw) })
+    }
+
     /// Return a String describing the controller's button and axis
     /// mappings
     #[doc(alias = "SDL_GameControllerMapping")]
@@ -460,7 +559,37 @@ impl GameController {
         }
     }
 
-    /// Get the position of the given `axis`
+    /// Return the product version of an opened controller, if available.
+    #[doc(alias = "SDL_GameControllerGetProductVersion")]
+    pub fn product_version(&self) -> Option<u16> {
+        joystick::non_zero(unsafe { sys::SDL_GameControllerGetProductVersion(self.raw) })
+    }
+
+    /// Return the firmware version of an opened controller, if available.
+    #[doc(alias = "SDL_GameControllerGetFirmwareVersion")]
+    pub fn firmware_version(&self) -> Option<u16> {
+        joystick::non_zero(unsafe { sys::SDL_GameControllerGetFirmwareVersion(self.raw) })
+    }
+
+    /// Return the serial number of an opened controller, if SDL exposes one for this device.
+    #[doc(alias = "SDL_GameControllerGetSerial")]
+    pub fn serial(&self) -> Option<String> {
+        let serial = unsafe { sys::SDL_GameControllerGetSerial(self.raw) };
+
+        if serial.is_null() {
+            None
+        } else {
+            Some(c_str_to_string(serial))
+        }
+    }
+
+    /// Get the current position of the given `axis`.
+    ///
+    /// Stick axes range from -32768 to 32767, with 0 at rest. Trigger axes range from 0
+    /// (released) to 32767 (fully pressed).
+    ///
+    /// The state is refreshed by the event loop; if controller events are disabled, call
+    /// `GameControllerSubsystem::update` before polling.
     #[doc(alias = "SDL_GameControllerGetAxis")]
     pub fn axis(&self, axis: Axis) -> i16 {
         // This interface is a bit messed up: 0 is a valid position
@@ -476,7 +605,39 @@ impl GameController {
         unsafe { sys::SDL_GameControllerGetAxis(self.raw, raw_axis) }
     }
 
-    /// Returns `true` if `button` is pressed.
+    /// Get the current position of the given `axis`, normalized to the range -1.0 to 1.0
+    /// (0.0 to 1.0 for triggers), with a deadzone applied.
+    ///
+    /// `deadzone` is a fraction of the full range of the axis, between 0.0 and 1.0: positions
+    /// within it are reported as 0.0. Positions outside of it are rescaled so that the output
+    /// starts at 0.0 on the edge of the deadzone and still reaches 1.0 at full deflection.
+    pub fn axis_normalized(&self, axis: Axis, deadzone: f32) -> f32 {
+        apply_deadzone(self.axis(axis), deadzone)
+    }
+
+    /// Get the current position of the left stick as an `(x, y)` vector, with a circular
+    /// deadzone applied.
+    ///
+    /// Unlike applying `axis_normalized` to each axis, the deadzone is based on the distance of
+    /// the stick from its center: `deadzone` is a fraction of the full range, and positions
+    /// within it are reported as `(0.0, 0.0)`. The magnitude of the vector is rescaled to start
+    /// at 0.0 on the edge of the deadzone and is clamped to 1.0.
+    pub fn left_stick(&self, deadzone: f32) -> (f32, f32) {
+        apply_circular_deadzone(self.axis(Axis::LeftX), self.axis(Axis::LeftY), deadzone)
+    }
+
+    /// Get the current position of the right stick as an `(x, y)` vector, with a circular
+    /// deadzone applied.
+    ///
+    /// See `left_stick` for how the deadzone is applied.
+    pub fn right_stick(&self, deadzone: f32) -> (f32, f32) {
+        apply_circular_deadzone(self.axis(Axis::RightX), self.axis(Axis::RightY), deadzone)
+    }
+
+    /// Returns `true` if `button` is currently pressed.
+    ///
+    /// The state is refreshed by the event loop; if controller events are disabled, call
+    /// `GameControllerSubsystem::update` before polling.
     #[doc(alias = "SDL_GameControllerGetButton")]
     pub fn button(&self, button: Button) -> bool {
         // This interface is a bit messed up: 0 is a valid position
@@ -578,6 +739,27 @@ impl GameController {
         }
     }
 
+    /// Retrieve the battery level of this controller.
+    ///
+    /// This is the same as `Joystick::power_level` for the underlying joystick.
+    #[doc(alias = "SDL_JoystickCurrentPowerLevel")]
+    pub fn power_level(&self) -> Result<joystick::PowerLevel, IntegerOrSdlError> {
+        joystick::power_level(unsafe { sys::SDL_GameControllerGetJoystick(self.raw) })
+    }
+
+    /// Opens the haptic device of the controller, for devices that expose force feedback
+    /// through the haptic API rather than `set_rumble`, such as racing wheels.
+    ///
+    /// Returns an error if the controller has no haptic support. The haptic device should be
+    /// dropped before the controller.
+    #[doc(alias = "SDL_HapticOpenFromJoystick")]
+    pub fn haptic(&self) -> Result<Haptic, String> {
+        let haptic_subsystem = self.subsystem.sdl().haptic()?;
+        unsafe {
+            haptic_subsystem.open_from_raw_joystick(sys::SDL_GameControllerGetJoystick(self.raw))
+        }
+    }
+
     /// Update a game controller's LED color.
     #[doc(alias = "SDL_GameControllerSetLED")]
     pub fn set_led(&mut self, red: u8, green: u8, blue: u8) -> Result<(), IntegerOrSdlError> {
@@ -712,6 +894,16 @@ fn c_str_to_string(c_str: *const c_char) -> String {
     }
 }
 
+/// Convert C string `c_str`, owned by SDL for the lifetime of the program, to a `&'static str`.
+/// Return an empty string if `c_str` is null.
+fn c_str_to_static_str(c_str: *const c_char) -> &'static str {
+    if c_str.is_null() {
+        ""
+    } else {
+        unsafe { CStr::from_ptr(c_str as *const _).to_str().unwrap() }
+    }
+}
+
 /// Convert C string `c_str` to a String. Return an SDL error if
 /// `c_str` is NULL.
 fn c_str_to_string_or_err(c_str: *const c_char) -> Result<String, String> {
@@ -726,3 +918,88 @@ fn c_str_to_string_or_err(c_str: *const c_char) -> Result<String, String> {
         })
     }
 }
+
+/// Normalizes a raw axis value to -1.0..1.0 and applies a scaled deadzone to it.
+pub(crate) fn apply_deadzone(value: i16, deadzone: f32) -> f32 {
+    let value = (value as f32 / i16::MAX as f32).clamp(-1.0, 1.0);
+    let deadzone = deadzone.clamp(0.0, 1.0);
+    if value.abs() <= deadzone || deadzone >= 1.0 {
+        0.0
+    } else {
+        value.signum() * (value.abs() - deadzone) / (1.0 - deadzone)
+    }
+}
+
+/// Normalizes a pair of raw axis values and applies a scaled deadzone to their magnitude.
+fn apply_circular_deadzone(x: i16, y: i16, deadzone: f32) -> (f32, f32) {
+    let x = (x as f32 / i16::MAX as f32).clamp(-1.0, 1.0);
+    let y = (y as f32 / i16::MAX as f32).clamp(-1.0, 1.0);
+    let deadzone = deadzone.clamp(0.0, 1.0);
+    let magnitude = x.hypot(y);
+    if magnitude <= deadzone || deadzone >= 1.0 {
+        (0.0, 0.0)
+    } else {
+        let scaled = (magnitude.min(1.0) - deadzone) / (1.0 - deadzone);
+        (x * scaled / magnitude, y * scaled / magnitude)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{apply_circular_deadzone, apply_deadzone};
+
+    #[test]
+    fn test_apply_deadzone() {
+        assert_eq!(apply_deadzone(0, 0.0), 0.0);
+        assert_eq!(apply_deadzone(i16::MAX, 0.0), 1.0);
+        assert_eq!(apply_deadzone(i16::MIN, 0.0), -1.0);
+
+        // inside the deadzone
+        assert_eq!(apply_deadzone(3000, 0.1), 0.0);
+        assert_eq!(apply_deadzone(-3000, 0.1), 0.0);
+
+        // full deflection is still reachable
+        assert_eq!(apply_deadzone(i16::MAX, 0.25), 1.0);
+        assert_eq!(apply_deadzone(i16::MIN, 0.25), -1.0);
+
+        // values just outside the deadzone start near 0
+        let just_outside = apply_deadzone(3400, 0.1);
+        assert!(just_outside > 0.0 && just_outside < 0.01);
+
+        // halfway between the deadzone and full deflection
+        let half = apply_deadzone(i16::MAX / 2, 0.0);
+        assert!((apply_deadzone((i16::MAX as f32 * 0.6) as i16, 0.2) - half).abs() < 0.001);
+
+        assert_eq!(apply_deadzone(i16::MAX, 1.0), 0.0);
+    }
+
+    #[test]
+    fn test_apply_circular_deadzone() {
+        assert_eq!(apply_circular_deadzone(0, 0, 0.2), (0.0, 0.0));
+
+        // on the diagonal, both axes and the magnitude are within the deadzone
+        let small = (i16::MAX as f32 * 0.15) as i16;
+        assert_eq!(apply_circular_deadzone(small, small, 0.25), (0.0, 0.0));
+
+        // each axis alone is within a square deadzone, but the magnitude (about 0.28) is outside
+        // of the circle, so only the circular deadzone keeps the input
+        let diagonal = (i16::MAX as f32 * 0.2) as i16;
+        assert_eq!(apply_deadzone(diagonal, 0.25), 0.0);
+        let (x, y) = apply_circular_deadzone(diagonal, diagonal, 0.25);
+        assert!(x > 0.0 && y > 0.0);
+        assert!((x - y).abs() < 0.0001);
+
+        // full deflection along an axis
+        let (x, y) = apply_circular_deadzone(i16::MAX, 0, 0.2);
+        assert_eq!((x, y), (1.0, 0.0));
+
+        // the magnitude is clamped to 1.0 in the corners
+        let (x, y) = apply_circular_deadzone(i16::MAX, i16::MIN, 0.2);
+        assert!((x.hypot(y) - 1.0).abs() < 0.0001);
+        assert!(x > 0.0 && y < 0.0);
+
+        // the direction is preserved
+        let (x, y) = apply_circular_deadzone(16000, 16000, 0.1);
+        assert!((x - y).abs() < 0.0001);
+    }
+}
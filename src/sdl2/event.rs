@@ -10,6 +10,7 @@ use std::mem;
 use std::mem::transmute;
 use std::ptr;
 use std::sync::Mutex;
+use std::time::Duration;
 
 use libc::c_int;
 use libc::c_void;
@@ -113,11 +114,96 @@ impl crate::EventSubsystem {
         }
     }
 
+    /// Returns the number of events in the event queue, without removing them.
+    ///
+    /// This is O(n) in the number of queued events, and is mainly intended to diagnose an event
+    /// queue backing up.
+    #[doc(alias = "SDL_PeepEvents")]
+    pub fn queue_len(&self) -> usize {
+        let result = unsafe {
+            sys::SDL_PeepEvents(
+                ptr::null_mut(),
+                0,
+                sys::SDL_eventaction::SDL_PEEKEVENT,
+                SDL_EventType::SDL_FIRSTEVENT as u32,
+                SDL_EventType::SDL_LASTEVENT as u32,
+            )
+        };
+
+        if result < 0 {
+            // The only error possible is "Couldn't lock event queue"
+            panic!("{}", get_error());
+        } else {
+            result as usize
+        }
+    }
+
     /// Pushes an event to the event queue.
     pub fn push_event(&self, event: Event) -> Result<(), String> {
         self.event_sender().push_event(event)
     }
 
+    /// Adds `events` to the back of the event queue, in order, for tests that drive an event
+    /// loop with scripted input.
+    ///
+    /// All events are converted before any is queued, so if one of them can't be converted to
+    /// an SDL event, nothing is added and an error is returned. If the queue fills up, the
+    /// events that fit, from the start of `events`, stay queued and an error is returned.
+    /// Unlike `push_event`, this bypasses event filters and watchers, like `SDL_PeepEvents`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use sdl2::event::Event;
+    ///
+    /// let sdl = sdl2::init().unwrap();
+    /// let ev = sdl.event().unwrap();
+    /// let mut event_pump = sdl.event_pump().unwrap();
+    ///
+    /// ev.inject(&[Event::Quit { timestamp: 0 }]).unwrap();
+    /// assert!(matches!(event_pump.poll_event(), Some(Event::Quit { .. })));
+    /// ```
+    #[doc(alias = "SDL_PeepEvents")]
+    pub fn inject(&self, events: &[Event]) -> Result<(), String> {
+        let mut raw_events = events
+            .iter()
+            .enumerate()
+            .map(|(index, event)| {
+                event.to_ll().ok_or_else(|| {
+                    format!(
+                        "Cannot push unsupported event type to the queue: event {} ({:?})",
+                        index, event
+                    )
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if raw_events.is_empty() {
+            return Ok(());
+        }
+
+        let len = c_int::try_from(raw_events.len()).map_err(|_| "Too many events".to_owned())?;
+        let result = unsafe {
+            sys::SDL_PeepEvents(
+                raw_events.as_mut_ptr(),
+                len,
+                sys::SDL_eventaction::SDL_ADDEVENT,
+                SDL_EventType::SDL_FIRSTEVENT as u32,
+                SDL_EventType::SDL_LASTEVENT as u32,
+            )
+        };
+
+        if result < 0 {
+            Err(get_error())
+        } else if result < len {
+            // The queue is full. SDL adds events one by one, so the first `result` are queued.
+            Err(format!(
+                "Event queue is full: only {} of {} events were added",
+                result, len
+            ))
+        } else {
+            Ok(())
+        }
+    }
+
     /// Register a custom SDL event.
     ///
     /// When pushing a user event, you must make sure that the ``type_`` field is set to a
@@ -1975,8 +2061,11 @@ impl Event {
                     timestamp: raw.common.timestamp,
                 },
 
-                EventType::First => panic!("Unused event, EventType::First, was encountered"),
-                EventType::Last => panic!("Unusable event, EventType::Last, was encountered"),
+                // These are never sent by SDL itself, but can be pushed by mistake.
+                EventType::First | EventType::Last => Event::Unknown {
+                    timestamp: raw.common.timestamp,
+                    type_: raw_type,
+                },
 
                 // If we have no other match and the event type is >= 32768
                 // this is a user event
@@ -2375,6 +2464,124 @@ impl Event {
         )
     }
 
+    /// Returns a copy of this event with its window coordinates converted into the logical
+    /// coordinate space of `canvas`, taking its logical size, scale and viewport into account.
+    ///
+    /// Mouse positions and relative motion become logical pixels. Finger positions and motion
+    /// stay normalized, but relative to the logical area instead of the whole window. Events
+    /// without coordinates are returned unchanged.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use sdl2::event::Event;
+    ///
+    /// fn on_click(event: &Event, canvas: &sdl2::render::WindowCanvas) {
+    ///     if let Event::MouseButtonDown { x, y, .. } = event.map_to_logical(canvas) {
+    ///         println!("clicked at logical ({}, {})", x, y);
+    ///     }
+    /// }
+    /// ```
+    #[doc(alias = "SDL_RenderWindowToLogical")]
+    pub fn map_to_logical(&self, canvas: &crate::render::WindowCanvas) -> Event {
+        let to_logical = |x: i32, y: i32| {
+            let mut logical_x = 0.0;
+            let mut logical_y = 0.0;
+            unsafe {
+                sys::SDL_RenderWindowToLogical(canvas.raw(), x, y, &mut logical_x, &mut logical_y)
+            };
+            (logical_x, logical_y)
+        };
+        let (origin_x, origin_y) = to_logical(0, 0);
+        let to_logical_rel = |xrel: i32, yrel: i32| {
+            let (x, y) = to_logical(xrel, yrel);
+            (x - origin_x, y - origin_y)
+        };
+
+        // finger coordinates are normalized to the window, so they are first converted to window
+        // coordinates, then normalized again against the logical area.
+        let (window_w, window_h) = canvas.window().size();
+        let (area_w, area_h) = match canvas.logical_size() {
+            (0, 0) => to_logical_rel(window_w as i32, window_h as i32),
+            (w, h) => (w as f32, h as f32),
+        };
+        let finger_to_logical = |x: f32, y: f32| {
+            let (x, y) = to_logical((x * window_w as f32) as i32, (y * window_h as f32) as i32);
+            (x / area_w, y / area_h)
+        };
+        let finger_to_logical_rel = |dx: f32, dy: f32| {
+            let (dx, dy) =
+                to_logical_rel((dx * window_w as f32) as i32, (dy * window_h as f32) as i32);
+            (dx / area_w, dy / area_h)
+        };
+
+        let mut event = self.clone();
+        match event {
+            Event::MouseMotion {
+                ref mut x,
+                ref mut y,
+                ref mut xrel,
+                ref mut yrel,
+                ..
+            } => {
+                let (logical_x, logical_y) = to_logical(*x, *y);
+                let (logical_xrel, logical_yrel) = to_logical_rel(*xrel, *yrel);
+                *x = logical_x as i32;
+                *y = logical_y as i32;
+                *xrel = logical_xrel as i32;
+                *yrel = logical_yrel as i32;
+            }
+            Event::MouseButtonDown {
+                ref mut x,
+                ref mut y,
+                ..
+            }
+            | Event::MouseButtonUp {
+                ref mut x,
+                ref mut y,
+                ..
+            }
+            | Event::MouseWheel {
+                mouse_x: ref mut x,
+                mouse_y: ref mut y,
+                ..
+            } => {
+                let (logical_x, logical_y) = to_logical(*x, *y);
+                *x = logical_x as i32;
+                *y = logical_y as i32;
+            }
+            Event::FingerDown {
+                ref mut x,
+                ref mut y,
+                ref mut dx,
+                ref mut dy,
+                ..
+            }
+            | Event::FingerUp {
+                ref mut x,
+                ref mut y,
+                ref mut dx,
+                ref mut dy,
+                ..
+            }
+            | Event::FingerMotion {
+                ref mut x,
+                ref mut y,
+                ref mut dx,
+                ref mut dy,
+                ..
+            } => {
+                let (logical_x, logical_y) = finger_to_logical(*x, *y);
+                let (logical_dx, logical_dy) = finger_to_logical_rel(*dx, *dy);
+                *x = logical_x;
+                *y = logical_y;
+                *dx = logical_dx;
+                *dy = logical_dy;
+            }
+            _ => {}
+        }
+        event
+    }
+
     /// Returns `true` if this is a controller event.
     ///
     /// # Example
@@ -2680,6 +2887,22 @@ impl crate::EventPump {
         result != sys::SDL_DISABLE as u8
     }
 
+    /// Returns whether an event of the specified type is in the event queue.
+    ///
+    /// The event is _not_ removed from the queue.
+    #[doc(alias = "SDL_HasEvent")]
+    pub fn has_event(&self, event_type: EventType) -> bool {
+        unsafe { sys::SDL_HasEvent(event_type as u32) == sys::SDL_bool::SDL_TRUE }
+    }
+
+    /// Returns whether any event in the specified type range is in the event queue.
+    ///
+    /// The events are _not_ removed from the queue.
+    #[doc(alias = "SDL_HasEvents")]
+    pub fn has_events(&self, min_type: u32, max_type: u32) -> bool {
+        unsafe { sys::SDL_HasEvents(min_type, max_type) == sys::SDL_bool::SDL_TRUE }
+    }
+
     /// Polls for currently pending events.
     ///
     /// If no events are pending, `None` is returned.
@@ -2727,6 +2950,15 @@ impl crate::EventPump {
         unsafe { wait_event_timeout(timeout) }
     }
 
+    /// Waits until `timeout` elapses for the next available event.
+    ///
+    /// The timeout is rounded down to whole milliseconds. Durations too long for SDL are clamped
+    /// to `i32::MAX` milliseconds (about 24 days) rather than truncated.
+    pub fn wait_event_timeout_duration(&mut self, timeout: Duration) -> Option<Event> {
+        let timeout = timeout.as_millis().min(i32::MAX as u128) as u32;
+        unsafe { wait_event_timeout(timeout) }
+    }
+
     /// Returns a waiting iterator that calls `wait_event()`.
     ///
     /// Note: The iterator will never terminate.
@@ -2983,6 +3215,27 @@ mod test {
     use super::DisplayEvent;
     use super::Event;
     use super::WindowEvent;
+    use crate::sys;
+    use std::mem;
+
+    #[test]
+    fn test_from_ll_sentinel_types() {
+        for &type_ in &[
+            sys::SDL_EventType::SDL_FIRSTEVENT as u32,
+            sys::SDL_EventType::SDL_LASTEVENT as u32,
+        ] {
+            let mut raw: sys::SDL_Event = unsafe { mem::zeroed() };
+            raw.type_ = type_;
+            raw.common.timestamp = 42;
+            match Event::from_ll(raw) {
+                Event::Unknown {
+                    timestamp: 42,
+                    type_: t,
+                } if t == type_ => {}
+                e => panic!("unexpected event {:?}", e),
+            }
+        }
+    }
 
     // Tests a round-trip conversion from an Event type to
     // the SDL event type and back, to make sure it's sane.
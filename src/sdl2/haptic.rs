@@ -12,15 +12,24 @@ impl HapticSubsystem {
         use crate::common::IntegerOrSdlError::*;
         let joystick_index = validate_int(joystick_index, "joystick_index")?;
 
-        let haptic = unsafe {
+        unsafe {
             let joystick = sys::SDL_JoystickOpen(joystick_index);
-            sys::SDL_HapticOpenFromJoystick(joystick)
-        };
+            self.open_from_raw_joystick(joystick).map_err(SdlError)
+        }
+    }
+
+    /// Returns the haptic device of an already opened joystick.
+    #[doc(alias = "SDL_HapticOpenFromJoystick")]
+    pub(crate) unsafe fn open_from_raw_joystick(
+        &self,
+        joystick: *mut sys::SDL_Joystick,
+    ) -> Result<Haptic, String> {
+        let haptic = sys::SDL_HapticOpenFromJoystick(joystick);
 
         if haptic.is_null() {
-            Err(SdlError(get_error()))
+            Err(get_error())
         } else {
-            unsafe { sys::SDL_HapticRumbleInit(haptic) };
+            sys::SDL_HapticRumbleInit(haptic);
             Ok(Haptic {
                 subsystem: self.clone(),
                 raw: haptic,
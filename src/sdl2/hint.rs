@@ -3,6 +3,11 @@ use libc::c_char;
 use std::ffi::{CStr, CString};
 
 const VIDEO_MINIMIZE_ON_FOCUS_LOSS: &str = "SDL_VIDEO_MINIMIZE_ON_FOCUS_LOSS";
+const TOUCH_MOUSE_EVENTS: &str = "SDL_TOUCH_MOUSE_EVENTS";
+const MOUSE_TOUCH_EVENTS: &str = "SDL_MOUSE_TOUCH_EVENTS";
+const MOUSE_RELATIVE_MODE_WARP: &str = "SDL_MOUSE_RELATIVE_MODE_WARP";
+const MOUSE_RELATIVE_WARP_MOTION: &str = "SDL_MOUSE_RELATIVE_WARP_MOTION";
+const MOUSE_RELATIVE_SYSTEM_SCALE: &str = "SDL_MOUSE_RELATIVE_SYSTEM_SCALE";
 
 pub enum Hint {
     Default,
@@ -74,6 +79,114 @@ pub fn get_video_minimize_on_focus_loss() -> bool {
     )
 }
 
+/// A hint that specifies whether touch events will generate synthetic mouse events.
+///
+/// [Official SDL documentation](https://wiki.libsdl.org/SDL_HINT_TOUCH_MOUSE_EVENTS)
+///
+/// # Default
+/// This is enabled by default.
+///
+/// # Example
+/// ```rust,no_run
+/// // Only handle touch events, without duplicate mouse events
+/// sdl2::hint::set_touch_mouse_events(false);
+/// ```
+///
+/// * `value`: `true` to generate mouse events from touch events, `false` to disable this feature.
+pub fn set_touch_mouse_events(value: bool) -> bool {
+    set(TOUCH_MOUSE_EVENTS, if value { "1" } else { "0" })
+}
+
+/// A hint that specifies whether mouse events will generate synthetic touch events.
+///
+/// [Official SDL documentation](https://wiki.libsdl.org/SDL_HINT_MOUSE_TOUCH_EVENTS)
+///
+/// # Default
+/// This is enabled by default on Android and iOS, and disabled on other platforms.
+///
+/// # Example
+/// ```rust,no_run
+/// sdl2::hint::set_mouse_touch_events(true);
+/// ```
+///
+/// * `value`: `true` to generate touch events from mouse events, `false` to disable this feature.
+pub fn set_mouse_touch_events(value: bool) -> bool {
+    set(MOUSE_TOUCH_EVENTS, if value { "1" } else { "0" })
+}
+
+/// A hint that specifies whether relative mouse mode is emulated by warping the cursor to the
+/// center of the window, instead of using raw input.
+///
+/// [Official SDL documentation](https://wiki.libsdl.org/SDL_HINT_MOUSE_RELATIVE_MODE_WARP)
+///
+/// # Default
+/// This is disabled by default. SDL uses raw input where the platform provides it, such as on
+/// Windows, macOS and X11 with XInput2, and only falls back to warping where it doesn't.
+/// Warping gives motion that follows the system pointer speed and acceleration, but can drop
+/// motion if the cursor hits the edge of the window between events.
+///
+/// # Example
+/// ```rust,no_run
+/// sdl2::hint::set_mouse_relative_mode_warp(true);
+/// ```
+///
+/// * `value`: `true` to always emulate relative mode by warping, `false` to use raw input when
+///   available.
+pub fn set_mouse_relative_mode_warp(value: bool) -> bool {
+    set(MOUSE_RELATIVE_MODE_WARP, if value { "1" } else { "0" })
+}
+
+/// A hint that specifies whether relative mouse mode is emulated by warping the cursor.
+/// See [`set_mouse_relative_mode_warp`].
+///
+/// # Default
+/// By default this will return `false`.
+pub fn get_mouse_relative_mode_warp() -> bool {
+    matches!(get(MOUSE_RELATIVE_MODE_WARP).as_deref(), Some("1"))
+}
+
+/// A hint that specifies whether warping the mouse while in relative mode generates a motion
+/// event.
+///
+/// [Official SDL documentation](https://wiki.libsdl.org/SDL_HINT_MOUSE_RELATIVE_WARP_MOTION)
+///
+/// # Default
+/// This is disabled by default, so calling `warp_mouse_in_window` in relative mode doesn't
+/// produce a jump in the relative motion.
+///
+/// # Example
+/// ```rust,no_run
+/// sdl2::hint::set_mouse_relative_warp_motion(false);
+/// ```
+///
+/// * `value`: `true` to generate motion events for warps in relative mode, `false` to
+///   suppress them.
+pub fn set_mouse_relative_warp_motion(value: bool) -> bool {
+    set(MOUSE_RELATIVE_WARP_MOTION, if value { "1" } else { "0" })
+}
+
+/// A hint that specifies whether relative mouse motion is affected by the system pointer speed
+/// and acceleration settings.
+///
+/// [Official SDL documentation](https://wiki.libsdl.org/SDL_HINT_MOUSE_RELATIVE_SYSTEM_SCALE)
+///
+/// # Default
+/// This is disabled by default, so relative motion uses the raw device movement. In SDL2 the
+/// system scale is only applied on Windows; other platforms ignore this hint. When relative mode
+/// is emulated by warping, motion always follows the system settings.
+///
+/// # Example
+/// ```rust,no_run
+/// // Make aiming speed match the desktop pointer speed.
+/// sdl2::hint::set_mouse_relative_system_scale(true);
+/// ```
+///
+/// * `value`: `true` to apply the system pointer scale to relative motion, `false` to use raw
+///   motion.
+pub fn set_mouse_relative_system_scale(value: bool) -> bool {
+    set(MOUSE_RELATIVE_SYSTEM_SCALE, if value { "1" } else { "0" })
+}
+
 #[doc(alias = "SDL_SetHint")]
 pub fn set(name: &str, value: &str) -> bool {
     let name = CString::new(name).unwrap();
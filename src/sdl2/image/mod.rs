@@ -39,6 +39,10 @@ bitflags! {
         const PNG  = image::IMG_InitFlags_IMG_INIT_PNG;
         const TIF  = image::IMG_InitFlags_IMG_INIT_TIF;
         const WEBP = image::IMG_InitFlags_IMG_INIT_WEBP;
+        /// Only supported by `SDL2_image` 2.6.0 and after; `init` can't load it otherwise.
+        const AVIF = 0x0000_0010;
+        /// Only supported by `SDL2_image` 2.6.0 and after; `init` can't load it otherwise.
+        const JXL  = 0x0000_0020;
     }
 }
 
@@ -57,6 +61,12 @@ impl ::std::fmt::Display for InitFlag {
         if self.contains(InitFlag::WEBP) {
             f.write_str("INIT_WEBP ")?;
         }
+        if self.contains(InitFlag::AVIF) {
+            f.write_str("INIT_AVIF ")?;
+        }
+        if self.contains(InitFlag::JXL) {
+            f.write_str("INIT_JXL ")?;
+        }
         Ok(())
     }
 }
@@ -199,6 +209,48 @@ pub fn init(flags: InitFlag) -> Result<Sdl2ImageContext, String> {
     }
 }
 
+/// Formats that `SDL2_image` decodes by itself, without loading a library in `init`.
+const BUILTIN_FORMATS: &[&str] = &[
+    "BMP", "CUR", "GIF", "ICO", "LBM", "PCX", "PNM", "SVG", "TGA", "XCF", "XPM", "XV",
+];
+
+/// Formats that require loading a library in `init`, with their flag.
+const INIT_FORMATS: &[(InitFlag, &str)] = &[
+    (InitFlag::JPG, "JPG"),
+    (InitFlag::PNG, "PNG"),
+    (InitFlag::TIF, "TIF"),
+    (InitFlag::WEBP, "WEBP"),
+    (InitFlag::AVIF, "AVIF"),
+    (InitFlag::JXL, "JXL"),
+];
+
+/// Returns the names of the image formats the linked `SDL2_image` can load, e.g. `"PNG"`.
+///
+/// The formats backed by an external library, such as PNG or WEBP, are probed by asking `init`
+/// to load all of them: this leaves the libraries it finds loaded until `SDL2_image` quits, as
+/// if they had been passed to `init`. The formats `SDL2_image` decodes by itself, such as BMP or
+/// GIF, are always reported, since they can't be probed at runtime; they are enabled by default
+/// but may be missing from custom builds.
+pub fn supported_formats() -> Vec<&'static str> {
+    let all = INIT_FORMATS
+        .iter()
+        .fold(InitFlag::empty(), |all, &(flag, _)| all | flag);
+    let supported = unsafe {
+        let used = image::IMG_Init(all.bits() as c_int);
+        InitFlag::from_bits_truncate(used as u32)
+    };
+
+    let mut formats: Vec<&'static str> = BUILTIN_FORMATS.to_vec();
+    formats.extend(
+        INIT_FORMATS
+            .iter()
+            .filter(|&&(flag, _)| supported.contains(flag))
+            .map(|&(_, name)| name),
+    );
+    formats.sort_unstable();
+    formats
+}
+
 /// Returns the version of the dynamically linked `SDL_image` library
 pub fn get_linked_version() -> Version {
     unsafe { Version::from_ll(*image::IMG_Linked_Version()) }
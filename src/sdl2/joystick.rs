@@ -3,6 +3,7 @@ use crate::sys::SDL_JoystickPowerLevel;
 
 use crate::clear_error;
 use crate::common::{validate_int, IntegerOrSdlError};
+use crate::controller::apply_deadzone;
 use crate::get_error;
 use crate::JoystickSubsystem;
 use libc::c_char;
@@ -79,6 +80,10 @@ impl JoystickSubsystem {
 
     /// If state is `true` joystick events are processed, otherwise
     /// they're ignored.
+    ///
+    /// Disabling joystick events is useful when polling the joystick state
+    /// manually, e.g. with `Joystick::axis` or `Joystick::button`. In that case,
+    /// call `update` before reading the state to get up to date values.
     #[doc(alias = "SDL_JoystickEventState")]
     pub fn set_event_state(&self, state: bool) {
         unsafe { sys::SDL_JoystickEventState(state as i32) };
@@ -189,29 +194,48 @@ impl Joystick {
         }
     }
 
-    /// Retrieve the battery level of this joystick
-    #[doc(alias = "SDL_JoystickCurrentPowerLevel")]
-    pub fn power_level(&self) -> Result<PowerLevel, IntegerOrSdlError> {
-        use crate::common::IntegerOrSdlError::*;
-        clear_error();
+    /// Return the USB vendor ID of the joystick, if available.
+    #[doc(alias = "SDL_JoystickGetVendor")]
+    pub fn vendor_id(&self) -> Option<u16> {
+        non_zero(unsafe { sys::SDL_JoystickGetVendor(self.raw) })
+    }
 
-        let result = unsafe { sys::SDL_JoystickCurrentPowerLevel(self.raw) };
+    /// Return the USB product ID of the joystick, if available.
+    #[doc(alias = "SDL_JoystickGetProduct")]
+    pub fn product_id(&self) -> Option<u16> {
+        non_zero(unsafe { sys::SDL_JoystickGetProduct(self.raw) })
+    }
 
-        let state = PowerLevel::from_ll(result);
+    /// Return the product version of the joystick, if available.
+    #[doc(alias = "SDL_JoystickGetProductVersion")]
+    pub fn product_version(&self) -> Option<u16> {
+        non_zero(unsafe { sys::SDL_JoystickGetProductVersion(self.raw) })
+    }
 
-        if result != SDL_JoystickPowerLevel::SDL_JOYSTICK_POWER_UNKNOWN {
-            Ok(state)
-        } else {
-            let err = get_error();
+    /// Return the firmware version of the joystick, if available.
+    #[doc(alias = "SDL_JoystickGetFirmwareVersion")]
+    pub fn firmware_version(&self) -> Option<u16> {
+        non_zero(unsafe { sys::SDL_JoystickGetFirmwareVersion(self.raw) })
+    }
 
-            if err.is_empty() {
-                Ok(state)
-            } else {
-                Err(SdlError(err))
-            }
+    /// Return the serial number of the joystick, if SDL exposes one for this device.
+    #[doc(alias = "SDL_JoystickGetSerial")]
+    pub fn serial(&self) -> Option<String> {
+        let serial = unsafe { sys::SDL_JoystickGetSerial(self.raw) };
+
+        if serial.is_null() {
+            None
+        } else {
+            Some(c_str_to_string(serial))
         }
     }
 
+    /// Retrieve the battery level of this joystick
+    #[doc(alias = "SDL_JoystickCurrentPowerLevel")]
+    pub fn power_level(&self) -> Result<PowerLevel, IntegerOrSdlError> {
+        power_level(self.raw)
+    }
+
     /// Retrieve the number of axes for this joystick
     #[doc(alias = "SDL_JoystickNumAxes")]
     pub fn num_axes(&self) -> u32 {
@@ -253,6 +277,16 @@ impl Joystick {
         }
     }
 
+    /// Gets the position of the given `axis`, normalized to the range -1.0 to 1.0, with a
+    /// deadzone applied.
+    ///
+    /// `deadzone` is a fraction of the full range of the axis, between 0.0 and 1.0, and is
+    /// applied the same way as `GameController::axis_normalized`. Errors are the same as
+    /// for `axis`.
+    pub fn axis_normalized(&self, axis: u32, deadzone: f32) -> Result<f32, IntegerOrSdlError> {
+        Ok(apply_deadzone(self.axis(axis)?, deadzone))
+    }
+
     /// Retrieve the number of buttons for this joystick
     #[doc(alias = "SDL_JoystickNumButtons")]
     pub fn num_buttons(&self) -> u32 {
@@ -624,6 +658,39 @@ impl HatState {
     }
 }
 
+/// Reads the battery level of `joystick`, which may belong to a game controller.
+pub(crate) fn power_level(
+    joystick: *mut sys::SDL_Joystick,
+) -> Result<PowerLevel, IntegerOrSdlError> {
+    use crate::common::IntegerOrSdlError::*;
+    clear_error();
+
+    let result = unsafe { sys::SDL_JoystickCurrentPowerLevel(joystick) };
+
+    let state = PowerLevel::from_ll(result);
+
+    if result != SDL_JoystickPowerLevel::SDL_JOYSTICK_POWER_UNKNOWN {
+        Ok(state)
+    } else {
+        let err = get_error();
+
+        if err.is_empty() {
+            Ok(state)
+        } else {
+            Err(SdlError(err))
+        }
+    }
+}
+
+/// SDL reports unavailable IDs and versions as 0.
+pub(crate) fn non_zero(value: u16) -> Option<u16> {
+    if value == 0 {
+        None
+    } else {
+        Some(value)
+    }
+}
+
 /// Convert C string `c_str` to a String. Return an empty string if
 /// `c_str` is NULL.
 fn c_str_to_string(c_str: *const c_char) -> String {
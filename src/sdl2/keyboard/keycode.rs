@@ -479,6 +479,10 @@ use crate::keyboard::Scancode;
 
 impl Keycode {
     /// Gets the virtual key from a scancode. Returns None if there is no corresponding virtual key.
+    ///
+    /// The mapping follows the current keyboard layout, not a fixed table: on an AZERTY layout,
+    /// `Scancode::Q` (the key labeled A there) maps to `Keycode::A`. The layout is only known
+    /// once the video subsystem is initialized.
     #[doc(alias = "SDL_GetKeyFromScancode")]
     pub fn from_scancode(scancode: Scancode) -> Option<Keycode> {
         const UNKNOWN: i32 = sys::SDL_KeyCode::SDLK_UNKNOWN as i32;
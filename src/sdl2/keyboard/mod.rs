@@ -13,8 +13,10 @@ use crate::sys;
 
 mod keycode;
 mod scancode;
+mod textinput;
 pub use self::keycode::Keycode;
 pub use self::scancode::Scancode;
+pub use self::textinput::TextInputBuffer;
 
 bitflags! {
     pub struct Mod: u16 {
@@ -105,6 +107,41 @@ impl<'a> KeyboardState<'a> {
     pub fn pressed_scancodes(&self) -> PressedScancodeIterator {
         self.scancodes().into_pressed_scancode_iter()
     }
+
+    /// Copies the current state of the keyboard into an owned `KeyboardSnapshot`, which isn't
+    /// tied to the `EventPump` and can be stored or sent to another thread.
+    pub fn snapshot(&self) -> KeyboardSnapshot {
+        let mut snapshot = KeyboardSnapshot::default();
+        for (index, &pressed) in self.keyboard_state.iter().enumerate() {
+            if pressed != 0 && index < Scancode::Num as usize {
+                snapshot.pressed[index / 64] |= 1 << (index % 64);
+            }
+        }
+        snapshot
+    }
+}
+
+const SNAPSHOT_WORDS: usize = (Scancode::Num as usize).div_ceil(64);
+
+/// An owned copy of the keyboard state, created with `KeyboardState::snapshot()`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct KeyboardSnapshot {
+    pressed: [u64; SNAPSHOT_WORDS],
+}
+
+impl KeyboardSnapshot {
+    /// Returns true if the scancode was pressed when the snapshot was taken.
+    pub fn is_pressed(&self, scancode: Scancode) -> bool {
+        let index = scancode as usize;
+        index < Scancode::Num as usize && self.pressed[index / 64] & (1 << (index % 64)) != 0
+    }
+
+    /// Returns an iterator of the scancodes that were pressed when the snapshot was taken.
+    pub fn pressed_scancodes(&self) -> impl Iterator<Item = Scancode> + '_ {
+        (0..Scancode::Num as i32)
+            .filter_map(Scancode::from_i32)
+            .filter(move |&scancode| self.is_pressed(scancode))
+    }
 }
 
 pub struct ScancodeIterator<'a> {
@@ -195,6 +232,50 @@ impl KeyboardUtil {
             sys::SDL_SetModState(transmute::<u32, sys::SDL_Keymod>(flags.bits() as u32));
         }
     }
+
+    /// Gets the scancode corresponding to the given key according to the current keyboard
+    /// layout. Returns None if there is no corresponding scancode.
+    ///
+    /// This is equivalent to `Scancode::from_keycode`.
+    #[doc(alias = "SDL_GetScancodeFromKey")]
+    pub fn scancode_from_keycode(&self, keycode: Keycode) -> Option<Scancode> {
+        Scancode::from_keycode(keycode)
+    }
+
+    /// Gets the key corresponding to the given scancode according to the current keyboard
+    /// layout. Returns None if there is no corresponding key.
+    ///
+    /// This is equivalent to `Keycode::from_scancode`, which is layout-aware as well. Use it to
+    /// show the key a player has to press for a binding stored as a scancode.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use sdl2::keyboard::Scancode;
+    ///
+    /// # fn run(sdl_context: sdl2::Sdl) {
+    /// // "Q" on QWERTY, "A" on AZERTY, "'" on Dvorak.
+    /// if let Some(keycode) = sdl_context.keyboard().keycode_from_scancode(Scancode::Q) {
+    ///     println!("Press [{}] to quit", keycode.name());
+    /// }
+    /// # }
+    /// ```
+    #[doc(alias = "SDL_GetKeyFromScancode")]
+    pub fn keycode_from_scancode(&self, scancode: Scancode) -> Option<Keycode> {
+        Keycode::from_scancode(scancode)
+    }
+
+    /// Clears the state of the keyboard, as if all keys had been released.
+    ///
+    /// This generates key up events for all pressed keys, which is useful to recover from keys
+    /// that appear stuck after the window lost focus.
+    ///
+    /// This requires SDL 2.24.0.
+    #[doc(alias = "SDL_ResetKeyboard")]
+    pub fn reset_keyboard(&self) {
+        unsafe {
+            sys::SDL_ResetKeyboard();
+        }
+    }
 }
 
 /// Text input utility functions. Access with `VideoSubsystem::text_input()`.
@@ -0,0 +1,226 @@
+use crate::event::Event;
+
+use super::Keycode;
+
+/// An editable line of text fed by text input events.
+///
+/// `TextInput` events insert committed text at the cursor, and `TextEditing` events update the
+/// IME composition, which is kept separately until the input method commits it. Backspace, the
+/// left and right arrow keys, and Enter are handled from `KeyDown` events, except while a
+/// composition is in progress, since the input method uses these keys itself.
+///
+/// The cursor is a byte offset into [`text`](Self::text) and is always on a `char` boundary.
+///
+/// # Example
+/// ```no_run
+/// use sdl2::keyboard::TextInputBuffer;
+///
+/// # fn run(sdl_context: sdl2::Sdl, video: sdl2::VideoSubsystem) -> Result<(), String> {
+/// let mut event_pump = sdl_context.event_pump()?;
+/// let mut input = TextInputBuffer::new();
+/// video.text_input().start();
+///
+/// for event in event_pump.wait_iter() {
+///     if input.handle_event(&event) {
+///         println!("entered: {}", input.take());
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct TextInputBuffer {
+    text: String,
+    cursor: usize,
+    composition: String,
+    composition_cursor: usize,
+}
+
+impl TextInputBuffer {
+    /// Creates an empty buffer.
+    pub fn new() -> TextInputBuffer {
+        TextInputBuffer::default()
+    }
+
+    /// Updates the buffer from a text input, text editing or key down event. Other events are
+    /// ignored.
+    ///
+    /// Returns `true` if Enter was pressed, leaving the text unchanged.
+    pub fn handle_event(&mut self, event: &Event) -> bool {
+        match event {
+            Event::TextInput { text, .. } => {
+                self.composition.clear();
+                self.composition_cursor = 0;
+                self.insert(text);
+            }
+            Event::TextEditing { text, start, .. } => {
+                self.composition.clear();
+                self.composition.push_str(text);
+                self.composition_cursor = (*start).max(0) as usize;
+            }
+            Event::KeyDown {
+                keycode: Some(keycode),
+                ..
+            } if self.composition.is_empty() => {
+                let keycode = *keycode;
+                if keycode == Keycode::BACKSPACE {
+                    self.backspace();
+                } else if keycode == Keycode::LEFT {
+                    let previous = self.text[..self.cursor].chars().next_back();
+                    self.cursor -= previous.map_or(0, char::len_utf8);
+                } else if keycode == Keycode::RIGHT {
+                    let next = self.text[self.cursor..].chars().next();
+                    self.cursor += next.map_or(0, char::len_utf8);
+                } else if keycode == Keycode::RETURN || keycode == Keycode::KP_ENTER {
+                    return true;
+                }
+            }
+            _ => {}
+        }
+        false
+    }
+
+    /// Inserts `text` at the cursor and moves the cursor after it.
+    pub fn insert(&mut self, text: &str) {
+        self.text.insert_str(self.cursor, text);
+        self.cursor += text.len();
+    }
+
+    /// Removes the `char` before the cursor, if any.
+    pub fn backspace(&mut self) {
+        if let Some(previous) = self.text[..self.cursor].chars().next_back() {
+            self.cursor -= previous.len_utf8();
+            self.text.remove(self.cursor);
+        }
+    }
+
+    /// Moves the cursor to the byte offset `cursor`.
+    ///
+    /// Offsets past the end of the text are clamped to its length, and offsets inside a
+    /// multibyte `char` are moved back to the start of that `char`.
+    pub fn set_cursor(&mut self, cursor: usize) {
+        let mut cursor = cursor.min(self.text.len());
+        while !self.text.is_char_boundary(cursor) {
+            cursor -= 1;
+        }
+        self.cursor = cursor;
+    }
+
+    /// Returns the cursor position as a byte offset into the text.
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    /// Returns the committed text.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Returns the text being composed by the input method, which is usually drawn underlined
+    /// at the cursor. It is empty when no composition is in progress.
+    pub fn composition(&self) -> &str {
+        &self.composition
+    }
+
+    /// Returns the input method's cursor within the composition, in `char`s.
+    pub fn composition_cursor(&self) -> usize {
+        self.composition_cursor
+    }
+
+    /// Clears the text and the composition.
+    pub fn clear(&mut self) {
+        *self = TextInputBuffer::default();
+    }
+
+    /// Returns the committed text and clears the buffer.
+    pub fn take(&mut self) -> String {
+        let text = std::mem::take(&mut self.text);
+        self.clear();
+        text
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::TextInputBuffer;
+    use crate::event::Event;
+    use crate::keyboard::{Keycode, Mod};
+
+    fn text_input(text: &str) -> Event {
+        Event::TextInput {
+            timestamp: 0,
+            window_id: 0,
+            text: text.to_owned(),
+        }
+    }
+
+    fn text_editing(text: &str, start: i32) -> Event {
+        Event::TextEditing {
+            timestamp: 0,
+            window_id: 0,
+            text: text.to_owned(),
+            start,
+            length: 0,
+        }
+    }
+
+    fn key_down(keycode: Keycode) -> Event {
+        Event::KeyDown {
+            timestamp: 0,
+            window_id: 0,
+            keycode: Some(keycode),
+            scancode: None,
+            keymod: Mod::NOMOD,
+            repeat: false,
+        }
+    }
+
+    #[test]
+    fn test_multibyte_editing() {
+        let mut input = TextInputBuffer::new();
+        input.handle_event(&text_input("héllo"));
+        input.handle_event(&text_input("🦀"));
+        assert_eq!(input.text(), "héllo🦀");
+        assert_eq!(input.cursor(), input.text().len());
+
+        input.handle_event(&key_down(Keycode::BACKSPACE));
+        assert_eq!(input.text(), "héllo");
+
+        for _ in 0..4 {
+            input.handle_event(&key_down(Keycode::LEFT));
+        }
+        assert_eq!(input.cursor(), 1);
+        input.handle_event(&key_down(Keycode::RIGHT));
+        input.backspace();
+        assert_eq!(input.text(), "hllo");
+        input.insert("ë");
+        assert_eq!(input.text(), "hëllo");
+
+        input.set_cursor(2);
+        assert_eq!(input.cursor(), 1);
+        input.set_cursor(100);
+        assert_eq!(input.cursor(), input.text().len());
+
+        assert!(input.handle_event(&key_down(Keycode::RETURN)));
+        assert_eq!(input.take(), "hëllo");
+        assert_eq!(input, TextInputBuffer::new());
+    }
+
+    #[test]
+    fn test_ime_composition() {
+        let mut input = TextInputBuffer::new();
+        input.handle_event(&text_editing("にほ", 2));
+        assert_eq!(input.composition(), "にほ");
+        assert_eq!(input.composition_cursor(), 2);
+
+        // The input method handles these keys while composing.
+        input.handle_event(&key_down(Keycode::BACKSPACE));
+        assert!(!input.handle_event(&key_down(Keycode::RETURN)));
+        assert_eq!(input.text(), "");
+
+        input.handle_event(&text_input("日本"));
+        assert_eq!(input.text(), "日本");
+        assert_eq!(input.composition(), "");
+        assert_eq!(input.cursor(), "日本".len());
+    }
+}
@@ -59,6 +59,10 @@ pub extern crate sdl2_sys as sys;
 
 #[cfg(feature = "gfx")]
 extern crate c_vec;
+#[cfg(feature = "serde")]
+extern crate serde;
+#[cfg(all(test, feature = "serde"))]
+extern crate serde_test;
 
 pub use crate::sdl::*;
 
@@ -107,3 +111,6 @@ pub use crate::common::IntegerOrSdlError;
 
 #[cfg(feature = "raw-window-handle")]
 pub mod raw_window_handle;
+
+#[cfg(feature = "serde")]
+mod serde_impls;
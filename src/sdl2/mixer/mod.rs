@@ -20,19 +20,24 @@
 //! features = ["mixer"]
 //! ```
 
-use audio::AudioFormatNum;
+use audio::{AudioFormatNum, AudioSpec};
 use get_error;
+#[cfg(feature = "mixer-tags")]
+use libc::c_char;
 use libc::c_void;
 use libc::{c_double, c_int, c_uint};
 use rwops::RWops;
 use std::borrow::ToOwned;
 use std::convert::TryInto;
 use std::default;
+use std::env;
 use std::ffi::{CStr, CString};
 use std::fmt;
 use std::marker::PhantomData;
 use std::path::Path;
+use std::ptr;
 use std::str::from_utf8;
+use std::sync::Mutex;
 use sys;
 use sys::mixer;
 use version::Version;
@@ -243,13 +248,64 @@ impl Chunk {
     /// buffer has to fit in 32-bit unsigned integer. The chunk takes ownership of the buffer.
     ///
     /// It's your responsibility to provide the audio data in the right format, as no conversion
-    /// will take place when using this method.
+    /// will take place when using this method. Use `from_pcm` to have the format checked.
     pub fn from_raw_buffer<T: AudioFormatNum>(buffer: Box<[T]>) -> Result<Chunk, String> {
         let len: u32 = std::mem::size_of_val(&*buffer).try_into().unwrap();
         let raw = unsafe { mixer::Mix_QuickLoad_RAW(Box::into_raw(buffer) as *mut u8, len) };
         Self::from_owned_raw(raw)
     }
 
+    /// Load chunk from PCM samples described by `spec`, checking that they match the format in
+    /// use by the opened audio device (see `query_spec()`).
+    ///
+    /// Unlike `from_raw_buffer`, this returns an error instead of playing garbage if the sample
+    /// type, frequency or number of channels differ from the mixer's. The samples of all
+    /// channels must be interleaved.
+    pub fn from_pcm<T: AudioFormatNum>(samples: Vec<T>, spec: &AudioSpec) -> Result<Chunk, String> {
+        let (frequency, format, channels) = query_spec()?;
+        let spec_format = spec.format as i32 as AudioFormat;
+
+        if T::audio_format() as i32 as AudioFormat != spec_format {
+            return Err(format!(
+                "sample type {:?} does not match the spec format {:?}",
+                T::audio_format(),
+                spec.format
+            ));
+        }
+        if spec_format != format {
+            return Err(format!(
+                "spec format {:?} does not match the mixer format {:#06x}",
+                spec.format, format
+            ));
+        }
+        if spec.freq != frequency {
+            return Err(format!(
+                "spec frequency {} Hz does not match the mixer frequency {} Hz",
+                spec.freq, frequency
+            ));
+        }
+        if spec.channels as i32 != channels {
+            return Err(format!(
+                "spec has {} channels, but the mixer has {}",
+                spec.channels, channels
+            ));
+        }
+        if spec.channels == 0
+            || !samples
+                .chunks_exact(spec.channels as usize)
+                .remainder()
+                .is_empty()
+        {
+            return Err(format!(
+                "{} samples can't be split evenly into {} channels",
+                samples.len(),
+                spec.channels
+            ));
+        }
+
+        Chunk::from_raw_buffer(samples.into_boxed_slice())
+    }
+
     fn from_owned_raw(raw: *mut mixer::Mix_Chunk) -> Result<Chunk, String> {
         if raw.is_null() {
             Err(get_error())
@@ -596,6 +652,55 @@ pub fn get_paused_channels_number() -> i32 {
     unsafe { mixer::Mix_Paused(-1) as i32 }
 }
 
+/// What `pause_all` paused, so that `resume_all` only resumes that.
+struct GlobalPause {
+    channels: Vec<Channel>,
+    music: bool,
+}
+
+static GLOBAL_PAUSE: Mutex<Option<GlobalPause>> = Mutex::new(None);
+
+/// Pauses the music and all the channels that are playing, e.g. when opening a pause menu.
+///
+/// Unlike `Channel::all().pause()`, the channels and music that were already paused are
+/// remembered, so that `resume_all` leaves them paused. Calling `pause_all` again before
+/// `resume_all` does nothing.
+pub fn pause_all() {
+    let mut global_pause = GLOBAL_PAUSE.lock().unwrap();
+    if global_pause.is_some() {
+        return;
+    }
+
+    let channels = (0..allocate_channels(-1))
+        .map(Channel)
+        .filter(|channel| channel.is_playing() && !channel.is_paused())
+        .collect::<Vec<_>>();
+    for channel in &channels {
+        channel.pause();
+    }
+    let music = Music::is_playing() && !Music::is_paused();
+    if music {
+        Music::pause();
+    }
+
+    *global_pause = Some(GlobalPause { channels, music });
+}
+
+/// Resumes the music and the channels paused by `pause_all`.
+///
+/// Channels and music that were already paused when `pause_all` was called stay paused. Does
+/// nothing if `pause_all` wasn't called.
+pub fn resume_all() {
+    if let Some(global_pause) = GLOBAL_PAUSE.lock().unwrap().take() {
+        for channel in global_pause.channels {
+            channel.resume();
+        }
+        if global_pause.music {
+            Music::resume();
+        }
+    }
+}
+
 // 4.4 Groups
 
 /// Reserve num channels from being used when playing samples when
@@ -700,6 +805,88 @@ pub fn get_music_decoder(index: i32) -> String {
     }
 }
 
+/// Set the soundfonts used to play MIDI music, e.g. with FluidSynth.
+///
+/// SDL_mixer splits the list on `;` as well as the platform's path list separator, so paths
+/// may contain neither. Returns an error if a path cannot be passed to SDL_mixer, or if
+/// SDL_mixer rejects the soundfonts.
+pub fn set_soundfonts(paths: &[&Path]) -> Result<(), String> {
+    if let Some(path) = paths
+        .iter()
+        .find(|path| path.to_string_lossy().contains(';'))
+    {
+        return Err(format!(
+            "soundfont path contains ';', which SDL_mixer treats as a separator: {}",
+            path.display()
+        ));
+    }
+    let joined = env::join_paths(paths).map_err(|err| err.to_string())?;
+    let joined = joined
+        .to_str()
+        .ok_or_else(|| "soundfont paths are not valid UTF-8".to_owned())?;
+    let c_paths = CString::new(joined).map_err(|err| err.to_string())?;
+    let ret = unsafe { mixer::Mix_SetSoundFonts(c_paths.as_ptr()) };
+    if ret == 0 {
+        Err(get_error())
+    } else {
+        Ok(())
+    }
+}
+
+/// Get the soundfonts used to play MIDI music, if any were set with `set_soundfonts` or the
+/// `SDL_SOUNDFONTS` environment variable.
+pub fn get_soundfonts() -> Vec<String> {
+    unsafe {
+        let paths = mixer::Mix_GetSoundFonts();
+        if paths.is_null() {
+            return Vec::new();
+        }
+        let paths = CStr::from_ptr(paths).to_string_lossy();
+        env::split_paths(paths.as_ref())
+            .map(|path| path.to_string_lossy().into_owned())
+            .collect()
+    }
+}
+
+/// Returns the current music volume, on a scale of 0 to 128.
+///
+/// This is the same as `Music::get_volume`.
+pub fn get_music_volume() -> i32 {
+    Music::get_volume()
+}
+
+/// Returns the title tag of the music currently playing, or `None` if no music is playing or
+/// the music is untagged.
+///
+/// Requires SDL_mixer 2.6.0 (the `mixer-tags` feature).
+#[cfg(feature = "mixer-tags")]
+pub fn playing_music_title() -> Option<String> {
+    // Unlike Mix_GetMusicTitle, this doesn't fall back to the file name for untagged music.
+    music_tag(|| unsafe { Mix_GetMusicTitleTag(ptr::null()) })
+}
+
+#[cfg(feature = "mixer-tags")]
+extern "C" {
+    fn Mix_GetMusicTitleTag(music: *const mixer::Mix_Music) -> *const c_char;
+    fn Mix_GetMusicArtistTag(music: *const mixer::Mix_Music) -> *const c_char;
+    fn Mix_GetMusicAlbumTag(music: *const mixer::Mix_Music) -> *const c_char;
+}
+
+/// Converts a music tag to a `String`, or `None` if the tag is empty.
+#[cfg(feature = "mixer-tags")]
+fn music_tag<F: FnOnce() -> *const c_char>(get_tag: F) -> Option<String> {
+    let tag = get_tag();
+    if tag.is_null() {
+        return None;
+    }
+    let tag = unsafe { CStr::from_ptr(tag) }.to_string_lossy();
+    if tag.is_empty() {
+        None
+    } else {
+        Some(tag.into_owned())
+    }
+}
+
 /// Music type enumerations
 #[repr(i32)]
 #[derive(Copy, Clone, PartialEq, Hash, Debug)]
@@ -808,6 +995,24 @@ impl<'a> Music<'a> {
         }
     }
 
+    /// The title tag of the music, or `None` if the music is untagged.
+    #[cfg(feature = "mixer-tags")]
+    pub fn title(&self) -> Option<String> {
+        music_tag(|| unsafe { Mix_GetMusicTitleTag(self.raw) })
+    }
+
+    /// The artist tag of the music, or `None` if the music is untagged.
+    #[cfg(feature = "mixer-tags")]
+    pub fn artist(&self) -> Option<String> {
+        music_tag(|| unsafe { Mix_GetMusicArtistTag(self.raw) })
+    }
+
+    /// The album tag of the music, or `None` if the music is untagged.
+    #[cfg(feature = "mixer-tags")]
+    pub fn album(&self) -> Option<String> {
+        music_tag(|| unsafe { Mix_GetMusicAlbumTag(self.raw) })
+    }
+
     /// Play the loaded music loop times through from start to finish. Pass -1 to loop forever.
     pub fn play(&self, loops: i32) -> Result<(), String> {
         let ret = unsafe { mixer::Mix_PlayMusic(self.raw, loops as c_int) };
@@ -970,4 +1175,136 @@ impl<'a> Music<'a> {
 
 // TODO: Mix_RegisterEffect
 // TODO: Mix_UnregisterEffect
-// TODO: Mix_SetPostMix
+type PostMixCallback = Box<dyn FnMut(&mut [u8]) + Send + 'static>;
+
+static mut POST_MIX_CALLBACK: Option<PostMixCallback> = None;
+
+unsafe extern "C" fn c_post_mix_callback(_udata: *mut c_void, stream: *mut u8, len: c_int) {
+    if stream.is_null() || len <= 0 {
+        return;
+    }
+    let buf = ::std::slice::from_raw_parts_mut(stream, len as usize);
+    match POST_MIX_CALLBACK {
+        None => (),
+        Some(ref mut cb) => cb(buf),
+    }
+}
+
+/// Sets a function that is called after all mixing is performed, with the final mixed stream
+/// in the format returned by `query_spec()`.
+///
+/// The function runs on the audio thread, and may modify the stream in place. Only one post-mix
+/// function can be set at a time; setting a new one replaces the previous one.
+#[doc(alias = "Mix_SetPostMix")]
+pub fn set_post_mix(f: impl FnMut(&mut [u8]) + Send + 'static) {
+    unsafe {
+        // unset from c first, so the audio thread never sees the callback being replaced
+        mixer::Mix_SetPostMix(None, ptr::null_mut());
+        POST_MIX_CALLBACK = Some(Box::new(f));
+        mixer::Mix_SetPostMix(Some(c_post_mix_callback), ptr::null_mut());
+    }
+}
+
+/// Removes the function set by `set_post_mix`, if any.
+pub fn unset_post_mix() {
+    unsafe {
+        mixer::Mix_SetPostMix(None, ptr::null_mut());
+        // unset from c, then rust, to avoid race condition
+        POST_MIX_CALLBACK = None;
+    }
+}
+
+/// Scales every sample of `buf`, interpreted as `format`, by `factor`, clamping the results to
+/// the range of the format.
+///
+/// Returns false, leaving `buf` untouched, if the format is not supported.
+fn scale_samples(buf: &mut [u8], format: AudioFormat, factor: f32) -> bool {
+    macro_rules! scale_int {
+        ($ty:ty, $from:ident, $to:ident, $center:expr) => {
+            for chunk in buf.chunks_exact_mut(::std::mem::size_of::<$ty>()) {
+                let sample = <$ty>::$from(chunk.try_into().unwrap());
+                let scaled = (sample as f64 - $center) * factor as f64 + $center;
+                let clamped = scaled.clamp(<$ty>::MIN as f64, <$ty>::MAX as f64);
+                chunk.copy_from_slice(&(clamped.round() as $ty).$to());
+            }
+        };
+    }
+
+    macro_rules! scale_float {
+        ($from:ident, $to:ident) => {
+            for chunk in buf.chunks_exact_mut(4) {
+                let sample = f32::$from(chunk.try_into().unwrap());
+                chunk.copy_from_slice(&(sample * factor).clamp(-1.0, 1.0).$to());
+            }
+        };
+    }
+
+    match format {
+        AUDIO_U8 => scale_int!(u8, from_ne_bytes, to_ne_bytes, 128.0),
+        AUDIO_S8 => scale_int!(i8, from_ne_bytes, to_ne_bytes, 0.0),
+        AUDIO_U16LSB => scale_int!(u16, from_le_bytes, to_le_bytes, 32768.0),
+        AUDIO_U16MSB => scale_int!(u16, from_be_bytes, to_be_bytes, 32768.0),
+        AUDIO_S16LSB => scale_int!(i16, from_le_bytes, to_le_bytes, 0.0),
+        AUDIO_S16MSB => scale_int!(i16, from_be_bytes, to_be_bytes, 0.0),
+        AUDIO_S32LSB => scale_int!(i32, from_le_bytes, to_le_bytes, 0.0),
+        AUDIO_S32MSB => scale_int!(i32, from_be_bytes, to_be_bytes, 0.0),
+        AUDIO_F32LSB => scale_float!(from_le_bytes, to_le_bytes),
+        AUDIO_F32MSB => scale_float!(from_be_bytes, to_be_bytes),
+        _ => return false,
+    }
+    true
+}
+
+/// Installs a post-mix function scaling every sample of the final mix by `factor`, independently
+/// of the channel and music volumes. A factor of `1.0` leaves the output unchanged; negative
+/// factors are treated as `0.0`. Samples are clamped to the range of the output format.
+///
+/// This uses `set_post_mix`, and so replaces any post-mix function previously set. The audio
+/// device must be open, since the output format is read from `query_spec()`.
+///
+/// ```no_run
+/// sdl2::mixer::set_master_volume(0.5).unwrap();
+/// ```
+pub fn set_master_volume(factor: f32) -> Result<(), String> {
+    let (_, format, _) = query_spec()?;
+    let factor = factor.max(0.0);
+    if !scale_samples(&mut [], format, factor) {
+        return Err(format!("unsupported audio format: {:#06x}", format));
+    }
+    set_post_mix(move |buf| {
+        scale_samples(buf, format, factor);
+    });
+    Ok(())
+}
+
+/// Removes the master volume installed by `set_master_volume`.
+///
+/// This is the same as `unset_post_mix`.
+pub fn clear_master_volume() {
+    unset_post_mix();
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_scale_samples_clamps() {
+        let mut buf = [0u8; 4];
+        buf[..2].copy_from_slice(&20000i16.to_le_bytes());
+        buf[2..].copy_from_slice(&(-100i16).to_le_bytes());
+        assert!(scale_samples(&mut buf, AUDIO_S16LSB, 2.0));
+        assert_eq!(i16::from_le_bytes([buf[0], buf[1]]), i16::MAX);
+        assert_eq!(i16::from_le_bytes([buf[2], buf[3]]), -200);
+
+        let mut buf = [255u8, 128];
+        assert!(scale_samples(&mut buf, AUDIO_U8, 0.5));
+        assert_eq!(buf, [192, 128]);
+
+        let mut buf = 0.75f32.to_be_bytes();
+        assert!(scale_samples(&mut buf, AUDIO_F32MSB, 2.0));
+        assert_eq!(f32::from_be_bytes(buf), 1.0);
+
+        assert!(!scale_samples(&mut [0u8; 4], 0x1234, 1.0));
+    }
+}
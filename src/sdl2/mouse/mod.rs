@@ -9,7 +9,7 @@ use crate::sys;
 use crate::sys::SDL_SystemCursor;
 
 mod relative;
-pub use self::relative::RelativeMouseState;
+pub use self::relative::{MouseDeltaAccumulator, RelativeMouseState};
 
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
 #[repr(u32)]
@@ -62,6 +62,9 @@ impl Cursor {
         }
     }
 
+    /// Creates a color cursor from a surface.
+    ///
+    /// Returns an error if the hotspot (`hot_x`, `hot_y`) does not lie within the surface.
     // TODO: figure out how to pass Surface in here correctly
     #[doc(alias = "SDL_CreateColorCursor")]
     pub fn from_surface<S: AsRef<SurfaceRef>>(
@@ -69,8 +72,17 @@ impl Cursor {
         hot_x: i32,
         hot_y: i32,
     ) -> Result<Cursor, String> {
+        let surface = surface.as_ref();
+        let (width, height) = surface.size();
+        if hot_x < 0 || hot_x as u32 >= width || hot_y < 0 || hot_y as u32 >= height {
+            return Err(format!(
+                "cursor hotspot ({}, {}) is outside of the {}x{} surface",
+                hot_x, hot_y, width, height
+            ));
+        }
+
         unsafe {
-            let raw = sys::SDL_CreateColorCursor(surface.as_ref().raw(), hot_x, hot_y);
+            let raw = sys::SDL_CreateColorCursor(surface.raw(), hot_x, hot_y);
 
             if raw.is_null() {
                 Err(get_error())
@@ -390,11 +402,27 @@ impl MouseUtil {
         }
     }
 
+    /// Like `set_relative_mouse_mode`, but when turning relative mode off, also moves the cursor
+    /// to the center of `window` so that it reappears at a predictable place.
+    pub fn set_relative_mouse_mode_with_recenter(&self, window: &video::Window, on: bool) {
+        self.set_relative_mouse_mode(on);
+        if !on {
+            let (width, height) = window.size();
+            self.warp_mouse_in_window(window, (width / 2) as i32, (height / 2) as i32);
+        }
+    }
+
     #[doc(alias = "SDL_GetRelativeMouseMode")]
     pub fn relative_mouse_mode(&self) -> bool {
         unsafe { sys::SDL_GetRelativeMouseMode() == sys::SDL_bool::SDL_TRUE }
     }
 
+    /// Returns `true` if relative mouse mode is set to be emulated by warping the cursor, instead
+    /// of using raw input. See `hint::set_mouse_relative_mode_warp`.
+    pub fn relative_mouse_warp(&self) -> bool {
+        crate::hint::get_mouse_relative_mode_warp()
+    }
+
     #[doc(alias = "SDL_ShowCursor")]
     pub fn is_cursor_showing(&self) -> bool {
         unsafe { sys::SDL_ShowCursor(crate::sys::SDL_QUERY) == 1 }
@@ -419,3 +447,47 @@ impl MouseUtil {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::{Cursor, MouseDeltaAccumulator, MouseState};
+    use crate::event::Event;
+    use crate::pixels::PixelFormatEnum;
+    use crate::surface::Surface;
+
+    #[test]
+    fn test_cursor_from_surface_hotspot_out_of_bounds() {
+        let surface = Surface::new(16, 8, PixelFormatEnum::RGBA8888).unwrap();
+
+        assert!(Cursor::from_surface(&surface, 16, 0).is_err());
+        assert!(Cursor::from_surface(&surface, 0, 8).is_err());
+        assert!(Cursor::from_surface(&surface, -1, 0).is_err());
+        assert!(Cursor::from_surface(&surface, 0, -1).is_err());
+    }
+
+    #[test]
+    fn test_mouse_delta_accumulator() {
+        let motion = |xrel, yrel| Event::MouseMotion {
+            timestamp: 0,
+            window_id: 0,
+            which: 0,
+            mousestate: MouseState::from_sdl_state(0),
+            x: 0,
+            y: 0,
+            xrel,
+            yrel,
+        };
+
+        let mut delta = MouseDeltaAccumulator::new();
+        delta.add_event(&motion(3, -1));
+        delta.add_event(&motion(2, -4));
+        delta.add_event(&Event::Quit { timestamp: 0 });
+        assert_eq!(delta.delta(), (5, -5));
+        assert_eq!(delta.take(), (5, -5));
+        assert_eq!(delta.take(), (0, 0));
+
+        delta.add(1, 1);
+        delta.reset();
+        assert_eq!(delta.delta(), (0, 0));
+    }
+}
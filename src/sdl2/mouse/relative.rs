@@ -1,3 +1,4 @@
+use crate::event::Event;
 use crate::EventPump;
 
 use crate::sys;
@@ -144,3 +145,67 @@ impl RelativeMouseState {
         self.mouse_buttons().into_pressed_buttons_iter()
     }
 }
+
+/// Sums relative mouse motion, so that the total movement since the last `take()` or `reset()`
+/// can be read even if several motion events occurred in between.
+///
+/// # Example
+/// ```no_run
+/// use sdl2::mouse::MouseDeltaAccumulator;
+///
+/// # let mut event_pump = sdl2::init().unwrap().event_pump().unwrap();
+/// let mut delta = MouseDeltaAccumulator::new();
+/// loop {
+///     for event in event_pump.poll_iter() {
+///         delta.add_event(&event);
+///     }
+///     let (dx, dy) = delta.take();
+///     // rotate the camera by (dx, dy)...
+/// }
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct MouseDeltaAccumulator {
+    x: i32,
+    y: i32,
+}
+
+impl MouseDeltaAccumulator {
+    pub fn new() -> MouseDeltaAccumulator {
+        MouseDeltaAccumulator::default()
+    }
+
+    /// Adds relative motion to the accumulated total.
+    pub fn add(&mut self, xrel: i32, yrel: i32) {
+        self.x = self.x.saturating_add(xrel);
+        self.y = self.y.saturating_add(yrel);
+    }
+
+    /// Adds the relative motion of a `MouseMotion` event. Other events are ignored.
+    pub fn add_event(&mut self, event: &Event) {
+        if let Event::MouseMotion { xrel, yrel, .. } = *event {
+            self.add(xrel, yrel);
+        }
+    }
+
+    /// Adds the motion reported by a `RelativeMouseState`.
+    pub fn add_state(&mut self, state: &RelativeMouseState) {
+        self.add(state.x(), state.y());
+    }
+
+    /// Returns the motion accumulated since the last `take()` or `reset()`.
+    pub fn delta(&self) -> (i32, i32) {
+        (self.x, self.y)
+    }
+
+    /// Discards the accumulated motion.
+    pub fn reset(&mut self) {
+        *self = MouseDeltaAccumulator::default();
+    }
+
+    /// Returns the accumulated motion and resets it.
+    pub fn take(&mut self) -> (i32, i32) {
+        let delta = self.delta();
+        self.reset();
+        delta
+    }
+}
@@ -67,6 +67,49 @@ impl Palette {
     pub fn is_empty(&self) -> bool {
         self.len() == 0
     }
+
+    /// Cyclically shifts the `count` entries starting at `start` by `by` places.
+    ///
+    /// A positive `by` moves each entry to a higher index, wrapping the last entries of the range
+    /// around to its start; a negative `by` moves them the other way. Calling this once per frame
+    /// gives classic palette-cycling effects on surfaces using this palette.
+    #[doc(alias = "SDL_SetPaletteColors")]
+    pub fn rotate(&mut self, start: usize, count: usize, by: i32) -> Result<(), String> {
+        let end = match start.checked_add(count) {
+            Some(end) if end <= self.len() => end,
+            _ => {
+                return Err(format!(
+                    "palette range {}..{} out of bounds for {} colors",
+                    start,
+                    start.saturating_add(count),
+                    self.len()
+                ))
+            }
+        };
+        if count == 0 {
+            return Ok(());
+        }
+
+        let all_colors = unsafe { std::slice::from_raw_parts((*self.raw).colors, self.len()) };
+        let mut colors = all_colors[start..end].to_vec();
+        let shift = (by as i64).rem_euclid(count as i64) as usize;
+        colors.rotate_right(shift);
+
+        let result = unsafe {
+            sys::SDL_SetPaletteColors(
+                self.raw,
+                colors.as_ptr(),
+                start as ::libc::c_int,
+                count as ::libc::c_int,
+            )
+        };
+
+        if result < 0 {
+            Err(get_error())
+        } else {
+            Ok(())
+        }
+    }
 }
 
 impl Drop for Palette {
@@ -89,6 +132,27 @@ fn create_palette() {
     assert!(palette.len() == 255);
 }
 
+#[test]
+fn rotate_palette() {
+    let colors: Vec<_> = (0..6).map(|u| Color::RGB(u, 0, 0)).collect();
+    let mut palette = Palette::with_colors(&colors).unwrap();
+
+    let reds = |palette: &Palette| -> Vec<u8> {
+        let raw = unsafe { std::slice::from_raw_parts((*palette.raw).colors, palette.len()) };
+        raw.iter().map(|color| color.r).collect()
+    };
+
+    palette.rotate(1, 4, 1).unwrap();
+    assert_eq!(reds(&palette), [0, 4, 1, 2, 3, 5]);
+    palette.rotate(1, 4, -1).unwrap();
+    assert_eq!(reds(&palette), [0, 1, 2, 3, 4, 5]);
+    palette.rotate(0, 6, 8).unwrap();
+    assert_eq!(reds(&palette), [4, 5, 0, 1, 2, 3]);
+
+    assert!(palette.rotate(4, 3, 1).is_err());
+    assert!(palette.rotate(6, 0, 1).is_ok());
+}
+
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
 pub struct Color {
     pub r: u8,
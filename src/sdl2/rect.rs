@@ -1369,6 +1369,27 @@ impl FRect {
         FRect::new(raw.x, raw.y, raw.w, raw.h)
     }
 
+    /// Converts this rectangle to a `Rect`, rounding its position and size to the nearest
+    /// integers.
+    ///
+    /// Like `Rect::new`, a size rounded to 0 becomes 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sdl2::rect::{FRect, Rect};
+    /// let rect = FRect::new(1.4, -2.6, 3.5, 0.2);
+    /// assert_eq!(rect.to_rect(), Rect::new(1, -3, 4, 1));
+    /// ```
+    pub fn to_rect(&self) -> Rect {
+        Rect::new(
+            self.x().round() as i32,
+            self.y().round() as i32,
+            self.width().round() as u32,
+            self.height().round() as u32,
+        )
+    }
+
     /// Calculate a minimal rectangle enclosing a set of points.
     /// If a clipping rectangle is given, only points that are within it will be
     /// considered.
@@ -1558,6 +1579,17 @@ impl From<FRect> for (f32, f32, f32, f32) {
     }
 }
 
+impl From<Rect> for FRect {
+    fn from(rect: Rect) -> FRect {
+        FRect::new(
+            rect.x() as f32,
+            rect.y() as f32,
+            rect.width() as f32,
+            rect.height() as f32,
+        )
+    }
+}
+
 impl From<sys::SDL_FRect> for FRect {
     fn from(raw: sys::SDL_FRect) -> FRect {
         FRect { raw }
@@ -1867,6 +1899,19 @@ mod test {
         );
     }
 
+    #[test]
+    fn contains_rect() {
+        let rect = Rect::new(0, 0, 10, 10);
+        assert!(rect.contains_rect(rect));
+        // touching the edges from the inside
+        assert!(rect.contains_rect(Rect::new(5, 5, 5, 5)));
+        // sticking out
+        assert!(!rect.contains_rect(Rect::new(5, 5, 6, 5)));
+        assert!(!rect.contains_rect(Rect::new(-1, 0, 5, 5)));
+        // enclosing
+        assert!(!rect.contains_rect(Rect::new(-1, -1, 12, 12)));
+    }
+
     #[test]
     fn has_intersection() {
         let rect = Rect::new(0, 0, 10, 10);
@@ -2131,6 +2176,22 @@ mod test {
         );
     }
 
+    #[test]
+    fn frect_from_rect() {
+        assert_eq!(
+            FRect::from(Rect::new(-11, 5, 50, 20)),
+            FRect::new(-11.0, 5.0, 50.0, 20.0)
+        );
+    }
+
+    #[test]
+    fn frect_to_rect() {
+        assert_eq!(
+            FRect::new(-11.4, 5.5, 49.6, 20.2).to_rect(),
+            Rect::new(-11, 6, 50, 20)
+        );
+    }
+
     #[test]
     fn fpoint_into() {
         let test: (f32, f32) = (-11.0, 5.0);
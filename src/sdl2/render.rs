@@ -52,6 +52,7 @@ use std::mem::{transmute, MaybeUninit};
 use std::ops::Deref;
 use std::ptr;
 use std::rc::Rc;
+use std::time::Duration;
 
 use crate::sys;
 use crate::sys::SDL_BlendMode;
@@ -95,6 +96,90 @@ impl Error for TargetRenderError {
     }
 }
 
+/// Possible errors returned by the copy and texture creation methods of `Canvas` and
+/// `TextureCreator`.
+///
+/// Existing code working with `String` errors keeps working: `RenderError` implements
+/// `Display`, so `.map_err(|e| e.to_string())` is still valid, and `From<RenderError> for String`
+/// allows using `?` in functions returning `Result<_, String>`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RenderError {
+    /// SDL2 returned with an error code.
+    SdlError(String),
+    /// The renderer does not support the use of render targets.
+    TargetNotSupported,
+    /// An integer argument is too big to be represented as a C integer.
+    IntegerOverflows(&'static str, u32),
+    /// The width of the texture must be a multiple of two for the given pixel format.
+    WidthMustBeMultipleOfTwoForFormat(u32, PixelFormatEnum),
+}
+
+impl fmt::Display for RenderError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use self::RenderError::*;
+
+        match *self {
+            SdlError(ref e) => write!(f, "SDL error: {}", e),
+            TargetNotSupported => {
+                write!(f, "The renderer does not support the use of render targets")
+            }
+            IntegerOverflows(name, value) => write!(f, "Integer '{}' overflows ({})", name, value),
+            WidthMustBeMultipleOfTwoForFormat(value, format) => {
+                write!(
+                    f,
+                    "Texture width must be multiple of two for pixel format '{:?}' ({})",
+                    format, value
+                )
+            }
+        }
+    }
+}
+
+impl Error for RenderError {}
+
+impl From<RenderError> for String {
+    fn from(e: RenderError) -> String {
+        e.to_string()
+    }
+}
+
+impl From<TextureValueError> for RenderError {
+    fn from(e: TextureValueError) -> RenderError {
+        match e {
+            TextureValueError::WidthOverflows(value) => {
+                RenderError::IntegerOverflows("width", value)
+            }
+            TextureValueError::HeightOverflows(value) => {
+                RenderError::IntegerOverflows("height", value)
+            }
+            TextureValueError::WidthMustBeMultipleOfTwoForFormat(value, format) => {
+                RenderError::WidthMustBeMultipleOfTwoForFormat(value, format)
+            }
+            TextureValueError::SdlError(e) => RenderError::SdlError(e),
+        }
+    }
+}
+
+impl From<TargetRenderError> for RenderError {
+    fn from(e: TargetRenderError) -> RenderError {
+        match e {
+            TargetRenderError::SdlError(SdlError(e)) => RenderError::SdlError(e),
+            TargetRenderError::NotSupported => RenderError::TargetNotSupported,
+        }
+    }
+}
+
+impl From<IntegerOrSdlError> for RenderError {
+    fn from(e: IntegerOrSdlError) -> RenderError {
+        match e {
+            IntegerOrSdlError::IntegerOverflows(name, value) => {
+                RenderError::IntegerOverflows(name, value)
+            }
+            IntegerOrSdlError::SdlError(e) => RenderError::SdlError(e),
+        }
+    }
+}
+
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
 #[repr(i32)]
 pub enum TextureAccess {
@@ -447,6 +532,22 @@ impl Canvas<Window> {
 
 impl<T: RenderTarget> Canvas<T> {
     /// Determine whether a window supports the use of render targets.
+    ///
+    /// Some renderers, such as old or software backends, can't render to textures. Checking this
+    /// up front allows falling back to drawing directly on the canvas instead; otherwise
+    /// `with_texture_canvas` and `with_multiple_texture_canvas` return
+    /// `TargetRenderError::NotSupported`.
+    ///
+    /// ```no_run
+    /// # use sdl2::render::Canvas;
+    /// # use sdl2::video::Window;
+    /// # let canvas: Canvas<Window> = unimplemented!();
+    /// if canvas.render_target_supported() {
+    ///     // draw effects to a target texture, then copy it to the canvas
+    /// } else {
+    ///     // draw directly to the canvas
+    /// }
+    /// ```
     #[doc(alias = "SDL_RenderTargetSupported")]
     pub fn render_target_supported(&self) -> bool {
         unsafe { sys::SDL_RenderTargetSupported(self.context.raw) == sys::SDL_bool::SDL_TRUE }
@@ -640,8 +741,67 @@ impl<T: RenderTarget> Canvas<T> {
             Err(TargetRenderError::NotSupported)
         }
     }
+
+    /// Renders into several target textures in sequence, each with its own closure.
+    ///
+    /// Each closure receives the `Canvas`, now targeting its texture, and the index of the
+    /// texture in `targets`. This is useful when a frame draws to several intermediate textures
+    /// that each need different drawing code.
+    ///
+    /// Like `with_multiple_texture_canvas`, render target support is checked once and the
+    /// original target is only restored after the last texture. For `n` textures this makes
+    /// `n + 1` calls to `SDL_SetRenderTarget`, where calling `with_texture_canvas` in a loop
+    /// makes `2 * n`.
+    ///
+    /// # Errors
+    ///
+    /// Same as `with_texture_canvas`. All textures must be created with
+    /// `TextureAccess::Target`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use sdl2::pixels::Color;
+    /// # use sdl2::render::{Canvas, Texture, TextureCanvasFn};
+    /// # use sdl2::video::Window;
+    /// # let mut canvas : Canvas<Window> = unimplemented!();
+    /// let texture_creator = canvas.texture_creator();
+    /// let mut lights = texture_creator.create_texture_target(None, 320, 240).unwrap();
+    /// let mut scene = texture_creator.create_texture_target(None, 320, 240).unwrap();
+    ///
+    /// let mut targets: [(&mut Texture, TextureCanvasFn<Window>); 2] = [
+    ///     (&mut lights, Box::new(|canvas, _| {
+    ///         canvas.set_draw_color(Color::RGB(255, 255, 200));
+    ///         canvas.clear();
+    ///     })),
+    ///     (&mut scene, Box::new(|canvas, _| {
+    ///         canvas.set_draw_color(Color::RGB(0, 0, 64));
+    ///         canvas.clear();
+    ///     })),
+    /// ];
+    /// canvas.with_texture_canvases(&mut targets).unwrap();
+    /// ```
+    pub fn with_texture_canvases<'a>(
+        &mut self,
+        targets: &mut [(&mut Texture, TextureCanvasFn<'a, T>)],
+    ) -> Result<(), TargetRenderError> {
+        if !self.render_target_supported() {
+            return Err(TargetRenderError::NotSupported);
+        }
+        let target = unsafe { self.get_raw_target() };
+        for (index, (texture, f)) in targets.iter_mut().enumerate() {
+            unsafe { self.set_raw_target(texture.raw) }.map_err(TargetRenderError::SdlError)?;
+            f(self, index);
+        }
+        // reset the target to its source
+        unsafe { self.set_raw_target(target) }.map_err(TargetRenderError::SdlError)?;
+        Ok(())
+    }
 }
 
+/// Drawing code for one of the textures passed to `Canvas::with_texture_canvases`.
+pub type TextureCanvasFn<'a, T> = Box<dyn FnMut(&mut Canvas<T>, usize) + 'a>;
+
 /// Creates Textures that cannot outlive the creator
 ///
 /// The `TextureCreator` does not hold a lifetime to its Canvas by design choice.
@@ -689,7 +849,13 @@ impl CanvasBuilder {
         use crate::common::IntegerOrSdlError::*;
         let index = match self.index {
             None => -1,
-            Some(index) => validate_int(index, "index")?,
+            Some(index) => {
+                let num_drivers = unsafe { sys::SDL_GetNumRenderDrivers() };
+                if index as i64 >= num_drivers as i64 {
+                    return Err(IntegerOverflows("index", index));
+                }
+                validate_int(index, "index")?
+            }
         };
         let raw = unsafe { sys::SDL_CreateRenderer(self.window.raw(), index, self.renderer_flags) };
 
@@ -710,6 +876,10 @@ impl CanvasBuilder {
     /// If you desire the first rendering driver to support the flags provided,
     /// or if you're translating code from C which passes -1 for the index,
     /// **do not** invoke the `index` method.
+    ///
+    /// The available drivers can be listed with `sdl2::render::drivers()`, in index order.
+    /// `build` returns `IntegerOrSdlError::IntegerOverflows("index", index)` if the index is
+    /// not less than the number of available drivers.
     pub fn index(mut self, index: u32) -> CanvasBuilder {
         self.index = Some(index);
         self
@@ -838,15 +1008,14 @@ impl<T> TextureCreator<T> {
         access: TextureAccess,
         width: u32,
         height: u32,
-    ) -> Result<Texture, TextureValueError>
+    ) -> Result<Texture, RenderError>
     where
         F: Into<Option<PixelFormatEnum>>,
     {
-        use self::TextureValueError::*;
         let format: PixelFormatEnum = format.into().unwrap_or(self.default_pixel_format);
         let result = ll_create_texture(self.context.raw(), format, access, width, height)?;
         if result.is_null() {
-            Err(SdlError(get_error()))
+            Err(RenderError::SdlError(get_error()))
         } else {
             unsafe { Ok(self.raw_create_texture(result)) }
         }
@@ -859,7 +1028,7 @@ impl<T> TextureCreator<T> {
         format: F,
         width: u32,
         height: u32,
-    ) -> Result<Texture, TextureValueError>
+    ) -> Result<Texture, RenderError>
     where
         F: Into<Option<PixelFormatEnum>>,
     {
@@ -873,7 +1042,7 @@ impl<T> TextureCreator<T> {
         format: F,
         width: u32,
         height: u32,
-    ) -> Result<Texture, TextureValueError>
+    ) -> Result<Texture, RenderError>
     where
         F: Into<Option<PixelFormatEnum>>,
     {
@@ -887,7 +1056,7 @@ impl<T> TextureCreator<T> {
         format: F,
         width: u32,
         height: u32,
-    ) -> Result<Texture, TextureValueError>
+    ) -> Result<Texture, RenderError>
     where
         F: Into<Option<PixelFormatEnum>>,
     {
@@ -928,12 +1097,11 @@ impl<T> TextureCreator<T> {
     pub fn create_texture_from_surface<S: AsRef<SurfaceRef>>(
         &self,
         surface: S,
-    ) -> Result<Texture, TextureValueError> {
-        use self::TextureValueError::*;
+    ) -> Result<Texture, RenderError> {
         let result =
             unsafe { sys::SDL_CreateTextureFromSurface(self.context.raw, surface.as_ref().raw()) };
         if result.is_null() {
-            Err(SdlError(get_error()))
+            Err(RenderError::SdlError(get_error()))
         } else {
             unsafe { Ok(self.raw_create_texture(result)) }
         }
@@ -976,6 +1144,20 @@ impl<T: RenderTarget> Canvas<T> {
         }
     }
 
+    /// Sets the color used for drawing operations from a color packed as `0xRRGGBBAA`.
+    ///
+    /// ```no_run
+    /// # use sdl2::render::Canvas;
+    /// # use sdl2::video::Window;
+    /// # let mut canvas: Canvas<Window> = unimplemented!();
+    /// // opaque orange
+    /// canvas.set_draw_color_u32(0xff8000ff);
+    /// ```
+    pub fn set_draw_color_u32(&mut self, packed: u32) {
+        let [r, g, b, a] = packed.to_be_bytes();
+        self.set_draw_color(pixels::Color::RGBA(r, g, b, a));
+    }
+
     /// Gets the color used for drawing operations (Rect, Line and Clear).
     #[doc(alias = "SDL_GetRenderDrawColor")]
     pub fn draw_color(&self) -> pixels::Color {
@@ -1037,6 +1219,23 @@ impl<T: RenderTarget> Canvas<T> {
         unsafe { sys::SDL_RenderPresent(self.context.raw) }
     }
 
+    /// Presents like `present()`, and returns the time spent in the call, measured with the
+    /// performance counter.
+    ///
+    /// With vsync enabled, the time includes the wait for the next vertical blank, so a frame
+    /// that consistently spends most of its time here is bound by the refresh rate or the GPU
+    /// rather than the CPU.
+    #[doc(alias = "SDL_RenderPresent")]
+    pub fn present_timed(&mut self) -> Duration {
+        let start = unsafe { sys::SDL_GetPerformanceCounter() };
+        self.present();
+        let end = unsafe { sys::SDL_GetPerformanceCounter() };
+
+        let ticks = end.wrapping_sub(start) as u128;
+        let frequency = unsafe { sys::SDL_GetPerformanceFrequency() } as u128;
+        Duration::from_nanos((ticks * 1_000_000_000 / frequency) as u64)
+    }
+
     /// Gets the output size of a rendering context.
     #[doc(alias = "SDL_GetRendererOutputSize")]
     pub fn output_size(&self) -> Result<(u32, u32), String> {
@@ -1128,6 +1327,9 @@ impl<T: RenderTarget> Canvas<T> {
     }
 
     /// Sets whether to force integer scales for resolution-independent rendering.
+    ///
+    /// This only has an effect when a logical size is set with `set_logical_size`. The current
+    /// value can be read back with `integer_scale`.
     #[doc(alias = "SDL_RenderSetIntegerScale")]
     pub fn set_integer_scale(&mut self, scale: bool) -> Result<(), String> {
         let ret = unsafe {
@@ -1461,7 +1663,7 @@ impl<T: RenderTarget> Canvas<T> {
     /// Errors if drawing fails for any reason (e.g. driver failure),
     /// or if the provided texture does not belong to the renderer.
     #[doc(alias = "SDL_RenderCopyF")]
-    pub fn copy_f<R1, R2>(&mut self, texture: &Texture, src: R1, dst: R2) -> Result<(), String>
+    pub fn copy_f<R1, R2>(&mut self, texture: &Texture, src: R1, dst: R2) -> Result<(), RenderError>
     where
         R1: Into<Option<Rect>>,
         R2: Into<Option<FRect>>,
@@ -1482,7 +1684,7 @@ impl<T: RenderTarget> Canvas<T> {
         };
 
         if ret != 0 {
-            Err(get_error())
+            Err(RenderError::SdlError(get_error()))
         } else {
             Ok(())
         }
@@ -1511,7 +1713,7 @@ impl<T: RenderTarget> Canvas<T> {
         center: P,
         flip_horizontal: bool,
         flip_vertical: bool,
-    ) -> Result<(), String>
+    ) -> Result<(), RenderError>
     where
         R1: Into<Option<Rect>>,
         R2: Into<Option<FRect>>,
@@ -1552,7 +1754,7 @@ impl<T: RenderTarget> Canvas<T> {
         };
 
         if ret != 0 {
-            Err(get_error())
+            Err(RenderError::SdlError(get_error()))
         } else {
             Ok(())
         }
@@ -1567,7 +1769,7 @@ impl<T: RenderTarget> Canvas<T> {
     /// Errors if drawing fails for any reason (e.g. driver failure),
     /// or if the provided texture does not belong to the renderer.
     #[doc(alias = "SDL_RenderCopy")]
-    pub fn copy<R1, R2>(&mut self, texture: &Texture, src: R1, dst: R2) -> Result<(), String>
+    pub fn copy<R1, R2>(&mut self, texture: &Texture, src: R1, dst: R2) -> Result<(), RenderError>
     where
         R1: Into<Option<Rect>>,
         R2: Into<Option<Rect>>,
@@ -1588,12 +1790,26 @@ impl<T: RenderTarget> Canvas<T> {
         };
 
         if ret != 0 {
-            Err(get_error())
+            Err(RenderError::SdlError(get_error()))
         } else {
             Ok(())
         }
     }
 
+    /// Copies the whole texture to the current rendering target, stretched to fill the current
+    /// viewport, e.g. to draw a background or a fullscreen image.
+    ///
+    /// Errors if drawing fails for any reason (e.g. driver failure),
+    /// or if the provided texture does not belong to the renderer.
+    #[doc(alias = "SDL_RenderCopy")]
+    pub fn copy_fill(&mut self, texture: &Texture) -> Result<(), RenderError> {
+        // The destination is relative to the viewport, in logical coordinates if a logical size
+        // is set, like the viewport itself.
+        let viewport = self.viewport();
+        let dst = Rect::new(0, 0, viewport.width(), viewport.height());
+        self.copy(texture, None, dst)
+    }
+
     /// Copies a portion of the texture to the current rendering target,
     /// optionally rotating it by angle around the given center and also
     /// flipping it top-bottom and/or left-right.
@@ -1617,7 +1833,7 @@ impl<T: RenderTarget> Canvas<T> {
         center: P,
         flip_horizontal: bool,
         flip_vertical: bool,
-    ) -> Result<(), String>
+    ) -> Result<(), RenderError>
     where
         R1: Into<Option<Rect>>,
         R2: Into<Option<Rect>>,
@@ -1658,7 +1874,7 @@ impl<T: RenderTarget> Canvas<T> {
         };
 
         if ret != 0 {
-            Err(get_error())
+            Err(RenderError::SdlError(get_error()))
         } else {
             Ok(())
         }
@@ -1705,6 +1921,44 @@ impl<T: RenderTarget> Canvas<T> {
         }
     }
 
+    /// Reads back the pixels of a target texture and returns a checksum of them, for visual
+    /// regression tests.
+    ///
+    /// The pixels are read as `PixelFormatEnum::RGBA32` and hashed with 64-bit FNV-1a, so the
+    /// checksum is the same across platforms and runs for the same image. The canvas's previous
+    /// render target is restored afterwards.
+    ///
+    /// The texture must be created with `TextureAccess::Target`; see `with_texture_canvas`.
+    ///
+    /// ```rust,no_run
+    /// # use sdl2::pixels::Color;
+    /// # use sdl2::render::{Canvas, Texture};
+    /// # use sdl2::video::Window;
+    /// # let mut canvas : Canvas<Window> = unimplemented!();
+    /// # let mut texture : Texture = unimplemented!();
+    /// canvas
+    ///     .with_texture_canvas(&mut texture, |texture_canvas| {
+    ///         texture_canvas.set_draw_color(Color::RED);
+    ///         texture_canvas.clear();
+    ///     })
+    ///     .unwrap();
+    /// let checksum = canvas.target_checksum(&mut texture).unwrap();
+    /// ```
+    #[doc(alias = "SDL_RenderReadPixels")]
+    pub fn target_checksum(&mut self, texture: &mut Texture) -> Result<u64, String> {
+        let mut read = Err(String::new());
+        self.with_texture_canvas(texture, |texture_canvas| {
+            read = texture_canvas.read_pixels(None, pixels::PixelFormatEnum::RGBA32);
+        })
+        .map_err(|e| e.to_string())?;
+
+        const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+        const FNV_PRIME: u64 = 0x0100_0000_01b3;
+        Ok(read?.iter().fold(FNV_OFFSET_BASIS, |hash, &byte| {
+            (hash ^ byte as u64).wrapping_mul(FNV_PRIME)
+        }))
+    }
+
     /// Creates a texture for a rendering context.
     ///
     /// If format is `None`, the format will be the one the parent Window or Surface uses.
@@ -1729,15 +1983,14 @@ impl<T: RenderTarget> Canvas<T> {
         access: TextureAccess,
         width: u32,
         height: u32,
-    ) -> Result<Texture, TextureValueError>
+    ) -> Result<Texture, RenderError>
     where
         F: Into<Option<PixelFormatEnum>>,
     {
-        use self::TextureValueError::*;
         let format: PixelFormatEnum = format.into().unwrap_or(self.default_pixel_format);
         let result = ll_create_texture(self.context.raw(), format, access, width, height)?;
         if result.is_null() {
-            Err(SdlError(get_error()))
+            Err(RenderError::SdlError(get_error()))
         } else {
             unsafe { Ok(self.raw_create_texture(result)) }
         }
@@ -1755,7 +2008,7 @@ impl<T: RenderTarget> Canvas<T> {
         format: F,
         width: u32,
         height: u32,
-    ) -> Result<Texture, TextureValueError>
+    ) -> Result<Texture, RenderError>
     where
         F: Into<Option<PixelFormatEnum>>,
     {
@@ -1774,7 +2027,7 @@ impl<T: RenderTarget> Canvas<T> {
         format: F,
         width: u32,
         height: u32,
-    ) -> Result<Texture, TextureValueError>
+    ) -> Result<Texture, RenderError>
     where
         F: Into<Option<PixelFormatEnum>>,
     {
@@ -1793,7 +2046,7 @@ impl<T: RenderTarget> Canvas<T> {
         format: F,
         width: u32,
         height: u32,
-    ) -> Result<Texture, TextureValueError>
+    ) -> Result<Texture, RenderError>
     where
         F: Into<Option<PixelFormatEnum>>,
     {
@@ -1814,12 +2067,11 @@ impl<T: RenderTarget> Canvas<T> {
     pub fn create_texture_from_surface<S: AsRef<SurfaceRef>>(
         &self,
         surface: S,
-    ) -> Result<Texture, TextureValueError> {
-        use self::TextureValueError::*;
+    ) -> Result<Texture, RenderError> {
         let result =
             unsafe { sys::SDL_CreateTextureFromSurface(self.context.raw, surface.as_ref().raw()) };
         if result.is_null() {
-            Err(SdlError(get_error()))
+            Err(RenderError::SdlError(get_error()))
         } else {
             unsafe { Ok(self.raw_create_texture(result)) }
         }
@@ -2381,6 +2633,32 @@ impl InternalTexture {
         }
     }
 
+    #[doc(alias = "SDL_RenderClear")]
+    pub fn clear<T: RenderTarget>(
+        &mut self,
+        canvas: &mut Canvas<T>,
+        color: pixels::Color,
+    ) -> Result<(), String> {
+        if self.query().access != TextureAccess::Target {
+            return Err("texture was not created with TextureAccess::Target".to_owned());
+        }
+        if !canvas.render_target_supported() {
+            return Err(TargetRenderError::NotSupported.to_string());
+        }
+
+        let previous_color = canvas.draw_color();
+        let previous_target = unsafe { canvas.context.get_raw_target() };
+        unsafe { canvas.context.set_raw_target(self.raw) }.map_err(|SdlError(e)| e)?;
+
+        canvas.set_draw_color(color);
+        let ret = unsafe { sys::SDL_RenderClear(canvas.context.raw) };
+        let result = if ret == 0 { Ok(()) } else { Err(get_error()) };
+
+        canvas.set_draw_color(previous_color);
+        unsafe { canvas.context.set_raw_target(previous_target) }.map_err(|SdlError(e)| e)?;
+        result
+    }
+
     pub unsafe fn gl_bind_texture(&mut self) -> (f32, f32) {
         let mut texw = 0.0;
         let mut texh = 0.0;
@@ -2521,6 +2799,21 @@ impl<'r> Texture<'r> {
         InternalTexture { raw: self.raw }.with_lock(rect, func)
     }
 
+    /// Fills the whole texture with `color` by temporarily making it the render target of
+    /// `canvas`.
+    ///
+    /// The previous render target and draw color of `canvas` are restored afterwards. The texture
+    /// must have been created with `TextureAccess::Target` by the same renderer as `canvas`;
+    /// otherwise an error is returned.
+    #[inline]
+    pub fn clear<T: RenderTarget>(
+        &mut self,
+        canvas: &mut Canvas<T>,
+        color: pixels::Color,
+    ) -> Result<(), String> {
+        InternalTexture { raw: self.raw }.clear(canvas, color)
+    }
+
     /// Binds an OpenGL/ES/ES2 texture to the current
     /// context for use with when rendering OpenGL primitives directly.
     #[inline]
@@ -2578,7 +2871,7 @@ impl<'r> Texture<'r> {
     pub fn from_surface<'a, T>(
         surface: &Surface,
         texture_creator: &'a TextureCreator<T>,
-    ) -> Result<Texture<'a>, TextureValueError> {
+    ) -> Result<Texture<'a>, RenderError> {
         texture_creator.create_texture_from_surface(surface)
     }
 
@@ -2612,7 +2905,7 @@ impl<'r> Texture<'r> {
     pub fn from_surface<T>(
         surface: &Surface,
         texture_creator: &TextureCreator<T>,
-    ) -> Result<Texture, TextureValueError> {
+    ) -> Result<Texture, RenderError> {
         texture_creator.create_texture_from_surface(surface)
     }
 }
@@ -2718,6 +3011,21 @@ impl Texture {
         InternalTexture { raw: self.raw }.with_lock(rect, func)
     }
 
+    /// Fills the whole texture with `color` by temporarily making it the render target of
+    /// `canvas`.
+    ///
+    /// The previous render target and draw color of `canvas` are restored afterwards. The texture
+    /// must have been created with `TextureAccess::Target` by the same renderer as `canvas`;
+    /// otherwise an error is returned.
+    #[inline]
+    pub fn clear<T: RenderTarget>(
+        &mut self,
+        canvas: &mut Canvas<T>,
+        color: pixels::Color,
+    ) -> Result<(), String> {
+        InternalTexture { raw: self.raw }.clear(canvas, color)
+    }
+
     /// Binds an OpenGL/ES/ES2 texture to the current
     /// context for use with when rendering OpenGL primitives directly.
     #[inline]
@@ -2840,3 +3148,163 @@ pub fn drivers() -> DriverIterator {
         index: 0,
     }
 }
+
+/// Two streaming textures of the same size and format, used as a front and a back buffer.
+///
+/// Writing a frame with [`with_back_buffer`](Self::with_back_buffer) locks the back texture,
+/// and once the closure returns that texture becomes the front one.
+/// [`present`](Self::present) always copies the most recently written texture, so uploading the
+/// next frame never touches the texture that is currently being drawn.
+///
+/// ```no_run
+/// use sdl2::pixels::PixelFormatEnum;
+/// use sdl2::render::StreamingTexturePair;
+///
+/// # fn run(mut canvas: sdl2::render::WindowCanvas) -> Result<(), String> {
+/// let creator = canvas.texture_creator();
+/// let mut pair = StreamingTexturePair::new(&creator, PixelFormatEnum::RGB24, 256, 256)
+///     .map_err(|e| e.to_string())?;
+///
+/// pair.with_back_buffer(|pixels, _pitch| pixels.fill(0x80))?;
+/// pair.present(&mut canvas, None).map_err(|e| e.to_string())?;
+/// canvas.present();
+/// # Ok(())
+/// # }
+/// ```
+pub struct StreamingTexturePair<'r> {
+    #[cfg(not(feature = "unsafe_textures"))]
+    textures: [Texture<'r>; 2],
+    #[cfg(feature = "unsafe_textures")]
+    textures: [Texture; 2],
+    #[cfg(feature = "unsafe_textures")]
+    _marker: std::marker::PhantomData<&'r ()>,
+    back: usize,
+    written: bool,
+}
+
+impl<'r> StreamingTexturePair<'r> {
+    /// Creates both streaming textures with `creator`.
+    pub fn new<T, F>(
+        creator: &'r TextureCreator<T>,
+        format: F,
+        width: u32,
+        height: u32,
+    ) -> Result<StreamingTexturePair<'r>, RenderError>
+    where
+        F: Into<Option<PixelFormatEnum>> + Copy,
+    {
+        let first = creator.create_texture_streaming(format, width, height)?;
+        let second = creator.create_texture_streaming(format, width, height)?;
+        Ok(StreamingTexturePair {
+            textures: [first, second],
+            #[cfg(feature = "unsafe_textures")]
+            _marker: std::marker::PhantomData,
+            back: 0,
+            written: false,
+        })
+    }
+
+    /// Locks the back texture and passes its pixels and pitch to `func`.
+    ///
+    /// When the lock succeeds the written texture becomes the front buffer, and the other
+    /// texture becomes the back buffer for the next call.
+    #[doc(alias = "SDL_LockTexture")]
+    pub fn with_back_buffer<F, R>(&mut self, func: F) -> Result<R, String>
+    where
+        F: FnOnce(&mut [u8], usize) -> R,
+    {
+        let result = self.textures[self.back].with_lock(None, func)?;
+        self.back = 1 - self.back;
+        self.written = true;
+        Ok(result)
+    }
+
+    /// Copies the most recently written texture to `dst` on `canvas`.
+    ///
+    /// If nothing has been written yet, this does nothing.
+    pub fn present<T, R>(&self, canvas: &mut Canvas<T>, dst: R) -> Result<(), RenderError>
+    where
+        T: RenderTarget,
+        R: Into<Option<Rect>>,
+    {
+        if !self.written {
+            return Ok(());
+        }
+        canvas.copy(self.front(), None, dst)
+    }
+
+    /// Returns the texture that [`present`](Self::present) copies.
+    #[cfg(not(feature = "unsafe_textures"))]
+    pub fn front(&self) -> &Texture<'r> {
+        &self.textures[1 - self.back]
+    }
+
+    /// Returns the texture that [`present`](Self::present) copies.
+    #[cfg(feature = "unsafe_textures")]
+    pub fn front(&self) -> &Texture {
+        &self.textures[1 - self.back]
+    }
+}
+
+const RENDER_SCALE_QUALITY: &str = "SDL_RENDER_SCALE_QUALITY";
+
+/// The filtering used when scaling textures, set with the `SDL_RENDER_SCALE_QUALITY` hint.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum ScaleQuality {
+    /// Nearest pixel sampling
+    Nearest,
+    /// Linear filtering, supported by OpenGL and Direct3D
+    Linear,
+    /// Anisotropic filtering, supported by Direct3D
+    Best,
+}
+
+impl ScaleQuality {
+    fn hint_value(self) -> &'static str {
+        match self {
+            ScaleQuality::Nearest => "nearest",
+            ScaleQuality::Linear => "linear",
+            ScaleQuality::Best => "best",
+        }
+    }
+}
+
+/// Runs `f` with the `SDL_RENDER_SCALE_QUALITY` hint set to `quality`, then restores the previous
+/// value of the hint, even if `f` panics.
+///
+/// The hint is read when a texture is created, so this affects the textures created by `f`.
+///
+/// This requires SDL 2.24.0, to restore an unset hint with `SDL_ResetHint`.
+///
+/// ```no_run
+/// # use sdl2::render::{ScaleQuality, TextureCreator};
+/// # use sdl2::video::WindowContext;
+/// # let texture_creator: TextureCreator<WindowContext> = unimplemented!();
+/// let pixel_art = sdl2::render::with_scale_quality(ScaleQuality::Nearest, || {
+///     texture_creator.create_texture_streaming(None, 64, 64)
+/// });
+/// ```
+pub fn with_scale_quality<F, R>(quality: ScaleQuality, f: F) -> R
+where
+    F: FnOnce() -> R,
+{
+    struct RestoreHint(Option<String>);
+
+    impl Drop for RestoreHint {
+        fn drop(&mut self) {
+            match self.0 {
+                Some(ref value) => {
+                    crate::hint::set(RENDER_SCALE_QUALITY, value);
+                }
+                None => unsafe {
+                    let name = std::ffi::CString::new(RENDER_SCALE_QUALITY).unwrap();
+                    sys::SDL_ResetHint(name.as_ptr());
+                },
+            }
+        }
+    }
+
+    let _restore = RestoreHint(crate::hint::get(RENDER_SCALE_QUALITY));
+    crate::hint::set(RENDER_SCALE_QUALITY, quality.hint_value());
+    f()
+}
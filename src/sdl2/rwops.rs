@@ -116,6 +116,32 @@ impl<'a> RWops<'a> {
         }
     }
 
+    /// Gets the stream's total size in bytes, like `len`, but returns SDL's error if the size
+    /// can't be determined.
+    #[doc(alias = "SDL_RWsize")]
+    pub fn size(&self) -> Result<u64, String> {
+        let result = unsafe { ((*self.raw).size.unwrap())(self.raw) };
+
+        if result < 0 {
+            Err(get_error())
+        } else {
+            Ok(result as u64)
+        }
+    }
+
+    /// Gets the current read/write offset in the stream, in bytes.
+    #[doc(alias = "SDL_RWtell")]
+    pub fn tell(&self) -> Result<u64, String> {
+        let result =
+            unsafe { ((*self.raw).seek.unwrap())(self.raw, 0, sys::RW_SEEK_CUR as libc::c_int) };
+
+        if result < 0 {
+            Err(get_error())
+        } else {
+            Ok(result as u64)
+        }
+    }
+
     // Tells if the stream is empty
     pub fn is_empty(&self) -> bool {
         match self.len() {
@@ -161,6 +161,70 @@ impl Sdl {
         EventPump::new(self)
     }
 
+    /// Returns which of the subsystems in `flags` are currently initialized.
+    #[doc(alias = "SDL_WasInit")]
+    pub fn was_init(&self, flags: InitFlags) -> InitFlags {
+        let result = unsafe { sys::SDL_WasInit(flags.bits()) };
+        InitFlags::from_bits_truncate(result) & flags
+    }
+
+    /// Reports the handles still keeping SDL alive besides this context, as pairs of a type name
+    /// and a count, and logs a warning with `sdl2::log::log` for each of them.
+    ///
+    /// Call this just before dropping the last `Sdl` context: any subsystem listed here, e.g.
+    /// through an `AudioDevice` or an `EventPump` that is still alive, keeps SDL initialized
+    /// after this context is dropped. The `"Sdl"` entry counts the other `Sdl` clones, along with
+    /// the `MouseUtil` and `KeyboardUtil` handles.
+    ///
+    /// Objects that don't hold a subsystem, such as textures created with the `unsafe_textures`
+    /// feature, can't be tracked. This is only available in debug builds.
+    ///
+    /// # Example
+    /// ```no_run
+    /// let sdl_context = sdl2::init().unwrap();
+    /// // ...
+    /// #[cfg(debug_assertions)]
+    /// debug_assert!(sdl_context.leak_check().is_empty());
+    /// ```
+    #[cfg(debug_assertions)]
+    pub fn leak_check(&self) -> Vec<(&'static str, u32)> {
+        let subsystems: [(&'static str, &AtomicU32); 8] = [
+            ("AudioSubsystem", &AUDIO_COUNT),
+            ("EventSubsystem", &EVENTS_COUNT),
+            ("GameControllerSubsystem", &GAMECONTROLLER_COUNT),
+            ("HapticSubsystem", &HAPTIC_COUNT),
+            ("JoystickSubsystem", &JOYSTICK_COUNT),
+            ("SensorSubsystem", &SENSOR_COUNT),
+            ("TimerSubsystem", &TIMER_COUNT),
+            ("VideoSubsystem", &VIDEO_COUNT),
+        ];
+
+        let mut leaks = Vec::new();
+        let mut subsystem_handles = 0;
+        for &(name, counter) in subsystems.iter() {
+            let count = counter.load(Ordering::Relaxed);
+            subsystem_handles += count;
+            if count > 0 {
+                leaks.push((name, count));
+            }
+        }
+        // Every subsystem handle holds an `SdlDrop` too, and one of them belongs to `self`.
+        let sdl_handles = SDL_COUNT
+            .load(Ordering::Relaxed)
+            .saturating_sub(1 + subsystem_handles);
+        if sdl_handles > 0 {
+            leaks.push(("Sdl", sdl_handles));
+        }
+
+        for &(name, count) in &leaks {
+            crate::log::log(&format!(
+                "warning: {} {} handle(s) still alive, SDL will not be quit with this context",
+                count, name
+            ));
+        }
+        leaks
+    }
+
     #[inline]
     #[doc(hidden)]
     pub fn sdldrop(&self) -> SdlDrop {
@@ -375,6 +439,82 @@ pub fn init() -> Result<Sdl, String> {
     Sdl::new()
 }
 
+/// Sets the name of the application, shown by the OS instead of "SDL Application", e.g. as the
+/// name of its audio stream in the system mixer, or in the D-Bus inhibition of the screensaver
+/// on Linux.
+///
+/// This must be called before initializing the subsystems using them, e.g. before opening an
+/// audio device. It is best called before `init()`.
+///
+/// `version` and `identifier` (e.g. `"com.example.game"`) are only checked: SDL2 has no use for
+/// them, they are accepted so that code can already provide the metadata SDL 3 reads.
+///
+/// Returns an error if an argument contains a nul byte or if the hints can't be set.
+///
+/// # Example
+/// ```no_run
+/// sdl2::set_app_metadata("My Game", "1.0.0", "com.example.mygame").unwrap();
+/// let sdl_context = sdl2::init().unwrap();
+/// ```
+#[doc(alias = "SDL_HINT_APP_NAME")]
+#[doc(alias = "SDL_HINT_AUDIO_DEVICE_APP_NAME")]
+pub fn set_app_metadata(name: &str, version: &str, identifier: &str) -> Result<(), String> {
+    for value in &[name, version, identifier] {
+        if value.contains('\0') {
+            return Err(format!(
+                "application metadata {:?} contains a nul byte",
+                value
+            ));
+        }
+    }
+    for hint in &["SDL_APP_NAME", "SDL_AUDIO_DEVICE_APP_NAME"] {
+        if !crate::hint::set(hint, name) {
+            return Err(format!("could not set the {} hint", hint));
+        }
+    }
+    Ok(())
+}
+
+bitflags! {
+    /// The subsystems to initialize with `init_subsystems()`.
+    pub struct InitFlags: u32 {
+        const TIMER = sys::SDL_INIT_TIMER;
+        const AUDIO = sys::SDL_INIT_AUDIO;
+        const VIDEO = sys::SDL_INIT_VIDEO;
+        const JOYSTICK = sys::SDL_INIT_JOYSTICK;
+        const HAPTIC = sys::SDL_INIT_HAPTIC;
+        const GAME_CONTROLLER = sys::SDL_INIT_GAMECONTROLLER;
+        const EVENTS = sys::SDL_INIT_EVENTS;
+        const SENSOR = sys::SDL_INIT_SENSOR;
+    }
+}
+
+/// Initializes the SDL library along with the subsystems in `flags`.
+///
+/// `init()` does not initialize any subsystem by itself; each one is initialized when it is
+/// first requested, e.g. with `Sdl::video()`, and quit when its last handle is dropped. The
+/// subsystems initialized here instead stay initialized until SDL is quit, so that devices
+/// are opened upfront and are not reopened as handles come and go. Subsystems missing from
+/// `flags` can still be requested later.
+///
+/// # Example
+/// ```no_run
+/// use sdl2::InitFlags;
+///
+/// let sdl_context = sdl2::init_subsystems(InitFlags::VIDEO | InitFlags::EVENTS).unwrap();
+/// let video_subsystem = sdl_context.video().unwrap();
+/// ```
+#[doc(alias = "SDL_InitSubSystem")]
+pub fn init_subsystems(flags: InitFlags) -> Result<Sdl, String> {
+    let sdl = Sdl::new()?;
+    let result = unsafe { sys::SDL_InitSubSystem(flags.bits()) };
+    if result != 0 {
+        Err(get_error())
+    } else {
+        Ok(sdl)
+    }
+}
+
 pub fn get_error() -> String {
     unsafe {
         let err = sys::SDL_GetError();
@@ -135,8 +135,12 @@ impl Sensor {
                 panic!("{}", get_error())
             }
             sys::SDL_SensorType::SDL_SENSOR_UNKNOWN => SensorType::Unknown,
-            sys::SDL_SensorType::SDL_SENSOR_ACCEL => SensorType::Accelerometer,
-            sys::SDL_SensorType::SDL_SENSOR_GYRO => SensorType::Gyroscope,
+            sys::SDL_SensorType::SDL_SENSOR_ACCEL
+            | sys::SDL_SensorType::SDL_SENSOR_ACCEL_L
+            | sys::SDL_SensorType::SDL_SENSOR_ACCEL_R => SensorType::Accelerometer,
+            sys::SDL_SensorType::SDL_SENSOR_GYRO
+            | sys::SDL_SensorType::SDL_SENSOR_GYRO_L
+            | sys::SDL_SensorType::SDL_SENSOR_GYRO_R => SensorType::Gyroscope,
         }
     }
 
@@ -158,6 +162,35 @@ impl Sensor {
             })
         }
     }
+
+    /// Get the current data from the sensor into `out`, without the fixed-size `SensorData`.
+    ///
+    /// Gyroscopes and accelerometers need at least 3 values; sensors of unknown type need at
+    /// least 1. Call `SensorSubsystem::update` first when not using the event loop.
+    #[doc(alias = "SDL_SensorGetData")]
+    pub fn get_data_into(&self, out: &mut [f32]) -> Result<(), String> {
+        let required = match self.sensor_type() {
+            SensorType::Gyroscope | SensorType::Accelerometer => 3,
+            SensorType::Unknown => 1,
+        };
+        if out.len() < required {
+            return Err(format!(
+                "{:?} sensor data needs at least {} values, got {}",
+                self.sensor_type(),
+                required,
+                out.len()
+            ));
+        }
+        let len = validate_int(out.len() as u32, "out.len()").map_err(|e| e.to_string())?;
+
+        let result = unsafe { SDL_SensorGetData(self.raw, out.as_mut_ptr(), len) };
+
+        if result != 0 {
+            Err(get_error())
+        } else {
+            Ok(())
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -0,0 +1,237 @@
+//! `Serialize` and `Deserialize` implementations, enabled with the `serde` feature.
+//!
+//! Enums and key codes are represented by their underlying integer values, the same ones used
+//! by SDL. Rectangles and points are represented as structs of their fields.
+
+use std::mem::transmute;
+
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::controller::{Axis, Button};
+use crate::keyboard::{Keycode, Scancode};
+use crate::mouse::MouseButton;
+use crate::pixels::Color;
+use crate::rect::{Point, Rect};
+use crate::sys;
+
+macro_rules! impl_serde_repr {
+    ($ty:ident, $repr:ident, $to:expr, $from:expr) => {
+        impl Serialize for $ty {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                let to: fn($ty) -> $repr = $to;
+                to(*self).serialize(serializer)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $ty {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<$ty, D::Error> {
+                let from: fn($repr) -> Option<$ty> = $from;
+                let value = $repr::deserialize(deserializer)?;
+                from(value).ok_or_else(|| {
+                    D::Error::custom(format!("invalid {} value: {}", stringify!($ty), value))
+                })
+            }
+        }
+    };
+}
+
+impl_serde_repr!(
+    Keycode,
+    i32,
+    |keycode| keycode.into_i32(),
+    Keycode::from_i32
+);
+impl_serde_repr!(
+    Scancode,
+    i32,
+    |scancode| scancode as i32,
+    Scancode::from_i32
+);
+impl_serde_repr!(MouseButton, u8, |button| button as u8, |value| Some(
+    MouseButton::from_ll(value)
+));
+impl_serde_repr!(Axis, i32, |axis| axis as i32, |value| {
+    let max = sys::SDL_GameControllerAxis::SDL_CONTROLLER_AXIS_MAX as i32;
+    if (0..max).contains(&value) {
+        Axis::from_ll(unsafe { transmute::<i32, sys::SDL_GameControllerAxis>(value) })
+    } else {
+        None
+    }
+});
+impl_serde_repr!(Button, i32, |button| button as i32, |value| {
+    let max = sys::SDL_GameControllerButton::SDL_CONTROLLER_BUTTON_MAX as i32;
+    if (0..max).contains(&value) {
+        Button::from_ll(unsafe { transmute::<i32, sys::SDL_GameControllerButton>(value) })
+    } else {
+        None
+    }
+});
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "Color")]
+struct ColorDef {
+    r: u8,
+    g: u8,
+    b: u8,
+    a: u8,
+}
+
+impl Serialize for Color {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let Color { r, g, b, a } = *self;
+        ColorDef { r, g, b, a }.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Color {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Color, D::Error> {
+        let ColorDef { r, g, b, a } = ColorDef::deserialize(deserializer)?;
+        Ok(Color::RGBA(r, g, b, a))
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "Rect")]
+struct RectDef {
+    x: i32,
+    y: i32,
+    w: u32,
+    h: u32,
+}
+
+impl Serialize for Rect {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        RectDef {
+            x: self.x(),
+            y: self.y(),
+            w: self.width(),
+            h: self.height(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Rect {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Rect, D::Error> {
+        let RectDef { x, y, w, h } = RectDef::deserialize(deserializer)?;
+        Ok(Rect::new(x, y, w, h))
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "Point")]
+struct PointDef {
+    x: i32,
+    y: i32,
+}
+
+impl Serialize for Point {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        PointDef {
+            x: self.x(),
+            y: self.y(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Point {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Point, D::Error> {
+        let PointDef { x, y } = PointDef::deserialize(deserializer)?;
+        Ok(Point::new(x, y))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_test::{assert_de_tokens_error, assert_tokens, Token};
+
+    use crate::controller::{Axis, Button};
+    use crate::keyboard::{Keycode, Scancode};
+    use crate::mouse::MouseButton;
+    use crate::pixels::Color;
+    use crate::rect::{Point, Rect};
+
+    #[test]
+    fn test_round_trip() {
+        assert_tokens(
+            &Rect::new(-10, 20, 30, 40),
+            &[
+                Token::Struct {
+                    name: "Rect",
+                    len: 4,
+                },
+                Token::Str("x"),
+                Token::I32(-10),
+                Token::Str("y"),
+                Token::I32(20),
+                Token::Str("w"),
+                Token::U32(30),
+                Token::Str("h"),
+                Token::U32(40),
+                Token::StructEnd,
+            ],
+        );
+        assert_tokens(
+            &Color::RGBA(1, 2, 3, 4),
+            &[
+                Token::Struct {
+                    name: "Color",
+                    len: 4,
+                },
+                Token::Str("r"),
+                Token::U8(1),
+                Token::Str("g"),
+                Token::U8(2),
+                Token::Str("b"),
+                Token::U8(3),
+                Token::Str("a"),
+                Token::U8(4),
+                Token::StructEnd,
+            ],
+        );
+        assert_tokens(
+            &Point::new(-5, 7),
+            &[
+                Token::Struct {
+                    name: "Point",
+                    len: 2,
+                },
+                Token::Str("x"),
+                Token::I32(-5),
+                Token::Str("y"),
+                Token::I32(7),
+                Token::StructEnd,
+            ],
+        );
+
+        assert_tokens(&Keycode::A, &[Token::I32('a' as i32)]);
+        assert_tokens(&Scancode::Escape, &[Token::I32(41)]);
+        assert_tokens(&MouseButton::Left, &[Token::U8(1)]);
+        assert_tokens(&MouseButton::X2, &[Token::U8(5)]);
+        assert_tokens(&Axis::TriggerRight, &[Token::I32(5)]);
+        assert_tokens(&Button::A, &[Token::I32(0)]);
+        assert_tokens(&Button::Touchpad, &[Token::I32(20)]);
+    }
+
+    #[test]
+    fn test_invalid_values() {
+        assert_de_tokens_error::<Axis>(&[Token::I32(100)], "invalid Axis value: 100");
+        assert_de_tokens_error::<Button>(&[Token::I32(21)], "invalid Button value: 21");
+        assert_de_tokens_error::<Button>(&[Token::I32(-1)], "invalid Button value: -1");
+
+        // `Color` fields must fit in `u8`.
+        assert_de_tokens_error::<Color>(
+            &[
+                Token::Struct {
+                    name: "Color",
+                    len: 4,
+                },
+                Token::Str("r"),
+                Token::U16(256),
+            ],
+            "invalid value: integer `256`, expected u8",
+        );
+    }
+}
@@ -6,12 +6,14 @@ use std::rc::Rc;
 
 use crate::get_error;
 use crate::pixels;
-use crate::rect::Rect;
+use crate::rect::{Point, Rect};
 use crate::render::{BlendMode, Canvas};
-use crate::render::{Texture, TextureCreator, TextureValueError};
+use crate::render::{RenderError, Texture, TextureCreator};
 use crate::rwops::RWops;
 use libc::c_int;
 use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt;
 use std::mem::transmute;
 use std::ptr;
 
@@ -63,6 +65,127 @@ impl AsRef<SurfaceRef> for SurfaceRef {
     }
 }
 
+/// The reasons `SurfaceRef::assert_blittable_to` can find for a blit to fail.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BlitError {
+    /// The source surface is locked.
+    SourceLocked,
+    /// The destination surface is locked.
+    DestinationLocked,
+    /// The source has a format the blitter can't read, such as a YUV format.
+    UnsupportedSourceFormat(pixels::PixelFormatEnum),
+    /// The destination has a format the blitter can't write, such as a YUV format or one with
+    /// less than 8 bits per pixel.
+    UnsupportedDestinationFormat(pixels::PixelFormatEnum),
+    /// The source blend mode isn't supported when either surface has an indexed format.
+    UnsupportedBlendMode {
+        blend_mode: BlendMode,
+        src_format: pixels::PixelFormatEnum,
+        dst_format: pixels::PixelFormatEnum,
+    },
+    /// The source color modulation isn't supported when either surface has an indexed format.
+    UnsupportedColorMod {
+        color_mod: pixels::Color,
+        src_format: pixels::PixelFormatEnum,
+        dst_format: pixels::PixelFormatEnum,
+    },
+}
+
+impl fmt::Display for BlitError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use self::BlitError::*;
+
+        match *self {
+            SourceLocked => write!(f, "Source surface is locked"),
+            DestinationLocked => write!(f, "Destination surface is locked"),
+            UnsupportedSourceFormat(format) => {
+                write!(f, "Can't blit from pixel format '{:?}'", format)
+            }
+            UnsupportedDestinationFormat(format) => {
+                write!(f, "Can't blit to pixel format '{:?}'", format)
+            }
+            UnsupportedBlendMode {
+                blend_mode,
+                src_format,
+                dst_format,
+            } => write!(
+                f,
+                "Blend mode '{:?}' isn't supported when blitting '{:?}' to '{:?}'",
+                blend_mode, src_format, dst_format
+            ),
+            UnsupportedColorMod {
+                color_mod,
+                src_format,
+                dst_format,
+            } => write!(
+                f,
+                "Color modulation {:?} isn't supported when blitting '{:?}' to '{:?}'",
+                color_mod, src_format, dst_format
+            ),
+        }
+    }
+}
+
+impl Error for BlitError {}
+
+/// Checks `SDL_ISPIXELFORMAT_FOURCC`.
+fn is_fourcc_format(format: u32) -> bool {
+    format != 0 && (format >> 28) & 0x0f != 1
+}
+
+/// Checks `SDL_ISPIXELFORMAT_INDEXED`.
+fn is_indexed_format(format: u32) -> bool {
+    let pixel_type = (format >> 24) & 0x0f;
+    !is_fourcc_format(format)
+        && (pixel_type == sys::SDL_PixelType::SDL_PIXELTYPE_INDEX1 as u32
+            || pixel_type == sys::SDL_PixelType::SDL_PIXELTYPE_INDEX4 as u32
+            || pixel_type == sys::SDL_PixelType::SDL_PIXELTYPE_INDEX8 as u32)
+}
+
+/// Checks that the masks fit within the bits per pixel and don't overlap, since SDL reports
+/// these mistakes as an unhelpful "Unknown pixel format" error.
+fn validate_pixelmasks(masks: &pixels::PixelMasks) -> Result<(), String> {
+    let all = [masks.rmask, masks.gmask, masks.bmask, masks.amask];
+
+    if masks.bpp == 0 || masks.bpp > 32 {
+        return Err(format!("Invalid bits per pixel: {}", masks.bpp));
+    }
+    if masks.bpp < 32 && all.iter().any(|&mask| mask >> masks.bpp != 0) {
+        return Err(format!(
+            "Pixel masks don't fit in {} bits per pixel.",
+            masks.bpp
+        ));
+    }
+    for (i, &a) in all.iter().enumerate() {
+        if all[i + 1..].iter().any(|&b| a & b != 0) {
+            return Err("Pixel masks overlap.".to_owned());
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_validate_pixelmasks() {
+    let rgba8888 = pixels::PixelMasks {
+        bpp: 32,
+        rmask: 0xff000000,
+        gmask: 0x00ff0000,
+        bmask: 0x0000ff00,
+        amask: 0x000000ff,
+    };
+    assert!(validate_pixelmasks(&rgba8888).is_ok());
+    assert!(validate_pixelmasks(&pixels::PixelMasks {
+        bpp: 24,
+        ..rgba8888
+    })
+    .is_err());
+    assert!(validate_pixelmasks(&pixels::PixelMasks {
+        gmask: 0xffff0000,
+        ..rgba8888
+    })
+    .is_err());
+}
+
 #[test]
 fn test_surface_ref_size() {
     // `SurfaceRef` must be 0 bytes.
@@ -130,6 +253,9 @@ impl<'a> Surface<'a> {
 
     /// Creates a new surface using pixel masks.
     ///
+    /// This allows channel layouts that don't correspond to a `PixelFormatEnum`. Returns an
+    /// error if the masks overlap or don't fit in `masks.bpp` bits.
+    ///
     /// # Example
     /// ```no_run
     /// use sdl2::pixels::PixelFormatEnum;
@@ -144,6 +270,7 @@ impl<'a> Surface<'a> {
         height: u32,
         masks: &pixels::PixelMasks,
     ) -> Result<Surface<'static>, String> {
+        validate_pixelmasks(masks)?;
         unsafe {
             if width >= (1 << 31) || height >= (1 << 31) {
                 Err("Image is too large.".to_owned())
@@ -189,6 +316,7 @@ impl<'a> Surface<'a> {
         pitch: u32,
         masks: &pixels::PixelMasks,
     ) -> Result<Surface<'a>, String> {
+        validate_pixelmasks(masks)?;
         unsafe {
             if width >= (1 << 31) || height >= (1 << 31) {
                 Err("Image is too large.".to_owned())
@@ -246,7 +374,7 @@ impl<'a> Surface<'a> {
     pub fn as_texture<'b, T>(
         &self,
         texture_creator: &'b TextureCreator<T>,
-    ) -> Result<Texture<'b>, TextureValueError> {
+    ) -> Result<Texture<'b>, RenderError> {
         texture_creator.create_texture_from_surface(self)
     }
 
@@ -280,7 +408,7 @@ impl<'a> Surface<'a> {
     pub fn as_texture<T>(
         &self,
         texture_creator: &TextureCreator<T>,
-    ) -> Result<Texture, TextureValueError> {
+    ) -> Result<Texture, RenderError> {
         texture_creator.create_texture_from_surface(self)
     }
 
@@ -316,6 +444,280 @@ impl<'a> Surface<'a> {
     }
 }
 
+/// Reads a pixel of 1 to 4 bytes, stored in native byte order.
+fn read_pixel(bytes: &[u8]) -> u32 {
+    let mut buf = [0; 4];
+    if cfg!(target_endian = "little") {
+        buf[..bytes.len()].copy_from_slice(bytes);
+        u32::from_le_bytes(buf)
+    } else {
+        buf[4 - bytes.len()..].copy_from_slice(bytes);
+        u32::from_be_bytes(buf)
+    }
+}
+
+/// Writes a pixel of 1 to 4 bytes in native byte order, the reverse of `read_pixel`.
+fn write_pixel(bytes: &mut [u8], pixel: u32) {
+    let len = bytes.len();
+    if cfg!(target_endian = "little") {
+        bytes.copy_from_slice(&pixel.to_le_bytes()[..len]);
+    } else {
+        bytes.copy_from_slice(&pixel.to_be_bytes()[4 - len..]);
+    }
+}
+
+/// An iterator over the pixels of a locked surface, created by `SurfaceRef::pixels`.
+pub struct PixelIter<'a> {
+    surface: &'a SurfaceRef,
+    pixels: &'a [u8],
+    bytes_per_pixel: usize,
+    x: u32,
+    y: u32,
+}
+
+impl<'a> Iterator for PixelIter<'a> {
+    type Item = (u32, u32, pixels::Color);
+
+    fn next(&mut self) -> Option<(u32, u32, pixels::Color)> {
+        let (width, height) = self.surface.size();
+        if width == 0 {
+            return None;
+        }
+        if self.x >= width {
+            self.x = 0;
+            self.y += 1;
+        }
+        if self.y >= height {
+            return None;
+        }
+
+        let (x, y) = (self.x, self.y);
+        self.x += 1;
+
+        let offset = y as usize * self.surface.pitch() as usize + x as usize * self.bytes_per_pixel;
+        let pixel = read_pixel(&self.pixels[offset..offset + self.bytes_per_pixel]);
+        let (mut r, mut g, mut b, mut a) = (0, 0, 0, 0);
+        unsafe {
+            sys::SDL_GetRGBA(
+                pixel,
+                self.surface.raw_ref().format,
+                &mut r,
+                &mut g,
+                &mut b,
+                &mut a,
+            )
+        };
+        Some((x, y, pixels::Color::RGBA(r, g, b, a)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (width, height) = self.surface.size();
+        let total = width as usize * height as usize;
+        let done = (self.y as usize * width as usize + self.x as usize).min(total);
+        (total - done, Some(total - done))
+    }
+}
+
+impl<'a> ExactSizeIterator for PixelIter<'a> {}
+
+impl<'a> Drop for PixelIter<'a> {
+    #[doc(alias = "SDL_UnlockSurface")]
+    fn drop(&mut self) {
+        unsafe { sys::SDL_UnlockSurface(self.surface.raw()) };
+    }
+}
+
+#[test]
+fn test_surface_pixels() {
+    use crate::pixels::{Color, PixelFormatEnum};
+
+    let mut surface = Surface::new(3, 2, PixelFormatEnum::RGBA8888).unwrap();
+    surface.fill_rect(None, Color::RGBA(0, 0, 0, 0)).unwrap();
+    surface
+        .fill_rect(Rect::new(1, 1, 1, 1), Color::RGBA(10, 20, 30, 40))
+        .unwrap();
+
+    let pixels = surface.pixels().unwrap();
+    assert_eq!(pixels.len(), 6);
+    let pixels: Vec<_> = pixels.collect();
+    assert_eq!(pixels[0], (0, 0, Color::RGBA(0, 0, 0, 0)));
+    assert_eq!(pixels[3], (0, 1, Color::RGBA(0, 0, 0, 0)));
+    assert_eq!(pixels[4], (1, 1, Color::RGBA(10, 20, 30, 40)));
+    assert_eq!(pixels.len(), 6);
+}
+
+#[test]
+fn test_surface_apply_color_matrix() {
+    use crate::pixels::{Color, PixelFormatEnum};
+
+    let mut surface = Surface::new(2, 1, PixelFormatEnum::ARGB8888).unwrap();
+    surface
+        .fill_rect(Rect::new(0, 0, 1, 1), Color::RGBA(200, 100, 50, 128))
+        .unwrap();
+    surface
+        .fill_rect(Rect::new(1, 0, 1, 1), Color::RGBA(10, 20, 30, 255))
+        .unwrap();
+
+    // Swap red and blue, then brighten green past its maximum.
+    #[rustfmt::skip]
+    let matrix = [
+        0.0, 0.0, 1.0, 0.0,
+        0.0, 2.0, 0.0, 100.0,
+        1.0, 0.0, 0.0, 0.0,
+    ];
+    surface.apply_color_matrix(matrix).unwrap();
+
+    let pixels: Vec<_> = surface
+        .pixels()
+        .unwrap()
+        .map(|(_, _, color)| color)
+        .collect();
+    assert_eq!(pixels[0], Color::RGBA(50, 255, 200, 128));
+    assert_eq!(pixels[1], Color::RGBA(30, 140, 10, 255));
+
+    let mut surface = Surface::new(1, 1, PixelFormatEnum::RGB24).unwrap();
+    assert!(surface.apply_color_matrix(matrix).is_err());
+}
+
+#[test]
+fn test_surface_get_set_pixel() {
+    use crate::pixels::{Color, PixelFormatEnum};
+
+    for &format in &[
+        PixelFormatEnum::RGB332,
+        PixelFormatEnum::RGB565,
+        PixelFormatEnum::RGB24,
+        PixelFormatEnum::BGR24,
+        PixelFormatEnum::RGBA8888,
+    ] {
+        let mut surface = Surface::new(3, 2, format).unwrap();
+        surface.set_pixel(2, 1, Color::RGB(255, 0, 0)).unwrap();
+        surface.set_pixel(1, 1, Color::RGB(0, 0, 255)).unwrap();
+        assert_eq!(surface.get_pixel(2, 1), Ok(Color::RGB(255, 0, 0)));
+        assert_eq!(surface.get_pixel(1, 1), Ok(Color::RGB(0, 0, 255)));
+        assert_eq!(surface.get_pixel(0, 0), Ok(Color::RGB(0, 0, 0)));
+        assert!(surface.get_pixel(3, 0).is_err());
+        assert!(surface.set_pixel(0, 2, Color::WHITE).is_err());
+    }
+
+    // The byte order in memory follows the format, whatever the host byte order.
+    let mut surface = Surface::new(1, 1, PixelFormatEnum::RGB24).unwrap();
+    surface.set_pixel(0, 0, Color::RGB(1, 2, 3)).unwrap();
+    assert_eq!(&surface.without_lock().unwrap()[..3], &[1, 2, 3]);
+}
+
+#[test]
+fn test_surface_premultiply_alpha() {
+    use crate::pixels::{Color, PixelFormatEnum};
+
+    for &format in &[
+        PixelFormatEnum::RGBA8888,
+        PixelFormatEnum::ABGR8888,
+        PixelFormatEnum::BGRA8888,
+    ] {
+        let mut surface = Surface::new(2, 1, format).unwrap();
+        surface
+            .set_pixel(0, 0, Color::RGBA(200, 100, 50, 128))
+            .unwrap();
+        surface
+            .set_pixel(1, 0, Color::RGBA(255, 255, 255, 255))
+            .unwrap();
+        surface.premultiply_alpha().unwrap();
+        assert_eq!(surface.get_pixel(0, 0), Ok(Color::RGBA(100, 50, 25, 128)));
+        assert_eq!(surface.get_pixel(1, 0), Ok(Color::RGBA(255, 255, 255, 255)));
+    }
+
+    let mut surface = Surface::new(1, 1, PixelFormatEnum::RGB24).unwrap();
+    assert!(surface.premultiply_alpha().is_err());
+}
+
+#[test]
+fn test_surface_rotated() {
+    use crate::pixels::{Color, PixelFormatEnum};
+
+    let mut surface = Surface::new(2, 1, PixelFormatEnum::RGBA8888).unwrap();
+    surface
+        .fill_rect(Rect::new(0, 0, 1, 1), Color::RGB(255, 0, 0))
+        .unwrap();
+    surface
+        .fill_rect(Rect::new(1, 0, 1, 1), Color::RGB(0, 0, 255))
+        .unwrap();
+
+    for &smooth in &[false, true] {
+        let rotated = surface.rotated(90.0, smooth).unwrap();
+        assert_eq!(rotated.size(), (1, 2));
+        let pixels: Vec<_> = rotated.pixels().unwrap().collect();
+        assert_eq!(pixels[0], (0, 0, Color::RGB(255, 0, 0)));
+        assert_eq!(pixels[1], (0, 1, Color::RGB(0, 0, 255)));
+    }
+
+    let rotated = surface.rotated(45.0, false).unwrap();
+    assert_eq!(rotated.size(), (3, 3));
+    let corner = rotated.pixels().unwrap().next().unwrap();
+    assert_eq!(corner, (0, 0, Color::RGBA(0, 0, 0, 0)));
+}
+
+#[test]
+fn test_surface_assert_blittable_to() {
+    use crate::pixels::PixelFormatEnum;
+
+    let mut src = Surface::new(4, 4, PixelFormatEnum::RGBA8888).unwrap();
+    let dst = Surface::new(4, 4, PixelFormatEnum::RGB888).unwrap();
+    let mut indexed = Surface::new(4, 4, PixelFormatEnum::Index8).unwrap();
+    let bitmap = Surface::new(8, 8, PixelFormatEnum::Index1LSB).unwrap();
+
+    assert_eq!(src.assert_blittable_to(&dst), Ok(()));
+    assert_eq!(src.assert_blittable_to(&indexed), Ok(()));
+    assert_eq!(
+        src.assert_blittable_to(&bitmap),
+        Err(BlitError::UnsupportedDestinationFormat(
+            PixelFormatEnum::Index1LSB
+        ))
+    );
+
+    src.set_blend_mode(BlendMode::Add).unwrap();
+    assert_eq!(src.assert_blittable_to(&dst), Ok(()));
+    assert_eq!(
+        src.assert_blittable_to(&indexed),
+        Err(BlitError::UnsupportedBlendMode {
+            blend_mode: BlendMode::Add,
+            src_format: PixelFormatEnum::RGBA8888,
+            dst_format: PixelFormatEnum::Index8,
+        })
+    );
+
+    indexed.set_color_mod(pixels::Color::RGB(255, 0, 0));
+    assert!(matches!(
+        indexed.assert_blittable_to(&dst),
+        Err(BlitError::UnsupportedColorMod { .. })
+    ));
+}
+
+#[test]
+fn test_surface_flood_fill() {
+    use crate::pixels::{Color, PixelFormatEnum};
+
+    let mut surface = Surface::new(4, 3, PixelFormatEnum::RGBA8888).unwrap();
+    surface.fill_rect(None, Color::RGB(0, 0, 0)).unwrap();
+    // A wall splitting the surface, with one pixel slightly lighter than the background.
+    surface
+        .fill_rect(Rect::new(2, 0, 1, 3), Color::RGB(255, 255, 255))
+        .unwrap();
+    surface
+        .fill_rect(Rect::new(1, 2, 1, 1), Color::RGB(3, 3, 3))
+        .unwrap();
+
+    let red = Color::RGB(255, 0, 0);
+    assert_eq!(surface.flood_fill(Point::new(0, 0), red, 0).unwrap(), 5);
+    assert_eq!(surface.flood_fill(Point::new(3, 0), red, 5).unwrap(), 3);
+    assert!(surface.flood_fill(Point::new(4, 0), red, 0).is_err());
+
+    let colors: Vec<_> = surface.pixels().unwrap().map(|(_, _, c)| c).collect();
+    assert_eq!(colors[0], red);
+    assert_eq!(colors[2], Color::RGB(255, 255, 255));
+    assert_eq!(colors[9], Color::RGB(3, 3, 3));
+}
+
 impl SurfaceRef {
     #[inline]
     pub unsafe fn from_ll<'a>(raw: *const sys::SDL_Surface) -> &'a SurfaceRef {
@@ -440,6 +842,72 @@ impl SurfaceRef {
         (self.raw_ref().flags & sys::SDL_RLEACCEL) != 0
     }
 
+    /// Converts the pixels of the surface from straight alpha to premultiplied alpha, in place.
+    ///
+    /// Only the 32-bit formats with an alpha channel (`ARGB8888`, `RGBA8888`, `ABGR8888` and
+    /// `BGRA8888`) are supported; other formats return an error. `ARGB8888` surfaces are
+    /// converted by SDL (which requires SDL 2.0.18), the other formats in software.
+    #[doc(alias = "SDL_PremultiplyAlpha")]
+    pub fn premultiply_alpha(&mut self) -> Result<(), String> {
+        use crate::pixels::PixelFormatEnum::*;
+
+        let format = self.pixel_format_enum();
+        if !matches!(format, ARGB8888 | RGBA8888 | ABGR8888 | BGRA8888) {
+            return Err(format!(
+                "Premultiplying alpha is not supported for {:?} surfaces",
+                format
+            ));
+        }
+
+        let (width, height) = self.size();
+        let pitch = self.pitch() as usize;
+        if format != ARGB8888 {
+            // SDL_PremultiplyAlpha only implements ARGB8888.
+            let raw_format = unsafe { &*self.raw_ref().format };
+            let alpha = (raw_format.Amask, raw_format.Ashift);
+            let colors = [
+                (raw_format.Rmask, raw_format.Rshift),
+                (raw_format.Gmask, raw_format.Gshift),
+                (raw_format.Bmask, raw_format.Bshift),
+            ];
+            self.with_lock_mut(|pixels| {
+                for row in pixels.chunks_mut(pitch) {
+                    for bytes in row[..width as usize * 4].chunks_exact_mut(4) {
+                        let pixel = read_pixel(bytes);
+                        let a = (pixel & alpha.0) >> alpha.1;
+                        let mut premultiplied = pixel & alpha.0;
+                        for &(mask, shift) in &colors {
+                            let c = (pixel & mask) >> shift;
+                            premultiplied |= (c * a / 255) << shift;
+                        }
+                        write_pixel(bytes, premultiplied);
+                    }
+                }
+            });
+            return Ok(());
+        }
+
+        let result = self.with_lock_mut(|pixels| unsafe {
+            let pixels = pixels.as_mut_ptr() as *mut libc::c_void;
+            sys::SDL_PremultiplyAlpha(
+                width as c_int,
+                height as c_int,
+                format as u32,
+                pixels,
+                pitch as c_int,
+                format as u32,
+                pixels,
+                pitch as c_int,
+            )
+        });
+
+        if result == 0 {
+            Ok(())
+        } else {
+            Err(get_error())
+        }
+    }
+
     #[doc(alias = "SDL_SaveBMP_RW")]
     pub fn save_bmp_rw(&self, rwops: &mut RWops) -> Result<(), String> {
         let ret = unsafe { sys::SDL_SaveBMP_RW(self.raw(), rwops.raw(), 0) };
@@ -455,6 +923,386 @@ impl SurfaceRef {
         self.save_bmp_rw(&mut file)
     }
 
+    /// Writes the surface as a binary PPM (P6) image, which can be viewed without requiring
+    /// the `image` feature.
+    ///
+    /// The alpha channel is ignored. This is mainly intended for debugging.
+    pub fn save_ppm_rw(&self, rwops: &mut RWops) -> Result<(), String> {
+        use std::io::Write;
+
+        let (width, height) = self.size();
+        let pitch = self.pitch() as usize;
+        let format = self.raw_ref().format;
+        let bytes_per_pixel = self.bytes_per_pixel()?;
+
+        let mut out = format!("P6\n{} {}\n255\n", width, height).into_bytes();
+        out.reserve(width as usize * height as usize * 3);
+        self.with_lock(|pixels| {
+            for row in pixels.chunks(pitch).take(height as usize) {
+                for bytes in row.chunks_exact(bytes_per_pixel).take(width as usize) {
+                    let pixel = read_pixel(bytes);
+                    let (mut r, mut g, mut b) = (0, 0, 0);
+                    unsafe { sys::SDL_GetRGB(pixel, format, &mut r, &mut g, &mut b) };
+                    out.extend_from_slice(&[r, g, b]);
+                }
+            }
+        });
+
+        rwops.write_all(&out).map_err(|e| e.to_string())
+    }
+
+    /// Writes the surface to a binary PPM (P6) file. See `save_ppm_rw`.
+    pub fn save_ppm<P: AsRef<Path>>(&self, path: P) -> Result<(), String> {
+        let mut file = RWops::from_file(path, "wb")?;
+        self.save_ppm_rw(&mut file)
+    }
+
+    /// Returns the number of bytes per pixel, or an error for formats packing several pixels per
+    /// byte.
+    fn bytes_per_pixel(&self) -> Result<usize, String> {
+        let format = self.raw_ref().format;
+        let (bits_per_pixel, bytes_per_pixel) =
+            unsafe { ((*format).BitsPerPixel, (*format).BytesPerPixel as usize) };
+        if bits_per_pixel < 8 || !(1..=4).contains(&bytes_per_pixel) {
+            Err(format!(
+                "Unsupported pixel format {:?}",
+                self.pixel_format_enum()
+            ))
+        } else {
+            Ok(bytes_per_pixel)
+        }
+    }
+
+    /// Returns the byte offset of the pixel at (`x`, `y`), or an error if it is outside of the
+    /// surface.
+    fn pixel_offset(&self, x: u32, y: u32, bytes_per_pixel: usize) -> Result<usize, String> {
+        let (width, height) = self.size();
+        if x >= width || y >= height {
+            Err(format!(
+                "pixel ({}, {}) is outside of the {}x{} surface",
+                x, y, width, height
+            ))
+        } else {
+            Ok(y as usize * self.pitch() as usize + x as usize * bytes_per_pixel)
+        }
+    }
+
+    /// Reads the color of the pixel at (`x`, `y`), locking the surface if needed.
+    ///
+    /// Pixels of 1 to 4 bytes are read in the host byte order, as SDL stores them, and indexed
+    /// formats are looked up in the palette.
+    ///
+    /// Returns an error if the pixel is outside of the surface, or if its format packs several
+    /// pixels per byte.
+    #[doc(alias = "SDL_GetRGBA")]
+    pub fn get_pixel(&self, x: u32, y: u32) -> Result<pixels::Color, String> {
+        let bytes_per_pixel = self.bytes_per_pixel()?;
+        let offset = self.pixel_offset(x, y, bytes_per_pixel)?;
+        let pixel = self.with_lock(|pixels| read_pixel(&pixels[offset..offset + bytes_per_pixel]));
+        Ok(pixels::Color::from_u32(&self.pixel_format(), pixel))
+    }
+
+    /// Sets the color of the pixel at (`x`, `y`), locking the surface if needed.
+    ///
+    /// This is the reverse of `get_pixel`. For indexed formats, the closest palette color is
+    /// used. Blend modes and color keys are ignored.
+    ///
+    /// Returns an error if the pixel is outside of the surface, or if its format packs several
+    /// pixels per byte.
+    #[doc(alias = "SDL_MapRGBA")]
+    pub fn set_pixel(&mut self, x: u32, y: u32, color: pixels::Color) -> Result<(), String> {
+        let bytes_per_pixel = self.bytes_per_pixel()?;
+        let offset = self.pixel_offset(x, y, bytes_per_pixel)?;
+        let pixel = color.to_u32(&self.pixel_format());
+        self.with_lock_mut(|pixels| {
+            write_pixel(&mut pixels[offset..offset + bytes_per_pixel], pixel)
+        });
+        Ok(())
+    }
+
+    /// Fills the area of similar color around `start` with `fill`, like the bucket tool of a
+    /// paint program, and returns the number of pixels filled.
+    ///
+    /// A pixel is part of the area if it is connected to `start` horizontally or vertically, and
+    /// each of its channels, alpha included, differs by at most `tolerance` from the color at
+    /// `start`.
+    ///
+    /// Returns an error if `start` is outside of the surface, or if its format packs several
+    /// pixels per byte.
+    pub fn flood_fill(
+        &mut self,
+        start: Point,
+        fill: pixels::Color,
+        tolerance: u8,
+    ) -> Result<u32, String> {
+        let (width, height) = self.size();
+        if start.x() < 0 || start.y() < 0 || start.x() as u32 >= width || start.y() as u32 >= height
+        {
+            return Err(format!(
+                "flood fill start ({}, {}) is outside of the {}x{} surface",
+                start.x(),
+                start.y(),
+                width,
+                height
+            ));
+        }
+
+        let bytes_per_pixel = self.bytes_per_pixel()?;
+        let pitch = self.pitch() as usize;
+        let format = self.raw_ref().format;
+        let (width, height) = (width as usize, height as usize);
+        let fill = fill.to_u32(&self.pixel_format());
+        let get_rgba = |pixel| {
+            let (mut r, mut g, mut b, mut a) = (0, 0, 0, 0);
+            unsafe { sys::SDL_GetRGBA(pixel, format, &mut r, &mut g, &mut b, &mut a) };
+            [r, g, b, a]
+        };
+
+        Ok(self.with_lock_mut(|pixels| {
+            let offset = |x: usize, y: usize| y * pitch + x * bytes_per_pixel;
+            let read = |pixels: &[u8], x, y| {
+                let offset = offset(x, y);
+                read_pixel(&pixels[offset..offset + bytes_per_pixel])
+            };
+
+            let target = get_rgba(read(pixels, start.x() as usize, start.y() as usize));
+            let mut visited = vec![false; width * height];
+            let mut matches = |pixels: &[u8], x: usize, y: usize| {
+                if visited[y * width + x] {
+                    return false;
+                }
+                let color = get_rgba(read(pixels, x, y));
+                let similar = color
+                    .iter()
+                    .zip(target.iter())
+                    .all(|(&c, &t)| c.abs_diff(t) <= tolerance);
+                if similar {
+                    visited[y * width + x] = true;
+                }
+                similar
+            };
+
+            let mut filled = 0;
+            let mut stack = vec![(start.x() as usize, start.y() as usize)];
+            while let Some((x, y)) = stack.pop() {
+                if !matches(pixels, x, y) {
+                    continue;
+                }
+                // Extend the span of matching pixels to the left and to the right.
+                let mut left = x;
+                while left > 0 && matches(pixels, left - 1, y) {
+                    left -= 1;
+                }
+                let mut right = x;
+                while right + 1 < width && matches(pixels, right + 1, y) {
+                    right += 1;
+                }
+
+                for x in left..=right {
+                    let offset = offset(x, y);
+                    write_pixel(&mut pixels[offset..offset + bytes_per_pixel], fill);
+                    if y > 0 {
+                        stack.push((x, y - 1));
+                    }
+                    if y + 1 < height {
+                        stack.push((x, y + 1));
+                    }
+                }
+                filled += (right - left + 1) as u32;
+            }
+            filled
+        }))
+    }
+
+    /// Applies a color transform to every pixel, in software.
+    ///
+    /// `matrix` holds three rows of four values, computing the new red, green and blue channels
+    /// in turn. Each row multiplies the old red, green and blue channels by its first three
+    /// values and adds the fourth value as an offset, in the 0-255 range. Results are clamped to
+    /// 0-255 and alpha is left unchanged.
+    ///
+    /// Returns an error unless the surface uses one of the 32-bit RGBA formats, such as
+    /// `PixelFormatEnum::RGBA8888` or `PixelFormatEnum::ARGB8888`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use sdl2::surface::Surface;
+    ///
+    /// fn grayscale(surface: &mut Surface) -> Result<(), String> {
+    ///     #[rustfmt::skip]
+    ///     let luma = [
+    ///         0.299, 0.587, 0.114, 0.0,
+    ///         0.299, 0.587, 0.114, 0.0,
+    ///         0.299, 0.587, 0.114, 0.0,
+    ///     ];
+    ///     surface.apply_color_matrix(luma)
+    /// }
+    /// ```
+    pub fn apply_color_matrix(&mut self, matrix: [f32; 12]) -> Result<(), String> {
+        use crate::pixels::PixelFormatEnum::*;
+        let format_enum = self.pixel_format_enum();
+        if !matches!(format_enum, RGBA8888 | ARGB8888 | ABGR8888 | BGRA8888) {
+            return Err(format!("Unsupported pixel format {:?}", format_enum));
+        }
+
+        let format = self.raw_ref().format;
+        let (width, height) = (self.width() as usize, self.height() as usize);
+        let pitch = self.pitch() as usize;
+
+        self.with_lock_mut(|pixels| {
+            for y in 0..height {
+                let row = &mut pixels[y * pitch..y * pitch + width * 4];
+                for bytes in row.chunks_exact_mut(4) {
+                    let (mut r, mut g, mut b, mut a) = (0, 0, 0, 0);
+                    unsafe {
+                        sys::SDL_GetRGBA(read_pixel(bytes), format, &mut r, &mut g, &mut b, &mut a)
+                    };
+                    let (r, g, b) = (r as f32, g as f32, b as f32);
+                    let channel = |row: &[f32]| {
+                        (row[0] * r + row[1] * g + row[2] * b + row[3])
+                            .round()
+                            .clamp(0.0, 255.0) as u8
+                    };
+                    let pixel = unsafe {
+                        sys::SDL_MapRGBA(
+                            format,
+                            channel(&matrix[0..4]),
+                            channel(&matrix[4..8]),
+                            channel(&matrix[8..12]),
+                            a,
+                        )
+                    };
+                    write_pixel(bytes, pixel);
+                }
+            }
+        });
+        Ok(())
+    }
+
+    /// Returns a copy of the surface rotated clockwise by `degrees`, sized to fit the rotated
+    /// bounding box, without requiring the `gfx` feature.
+    ///
+    /// Pixels are sampled with nearest-neighbor, or with bilinear filtering if `smooth` is
+    /// `true`. The result uses `PixelFormatEnum::RGBA32`, and areas not covered by the source
+    /// surface are transparent.
+    pub fn rotated(&self, degrees: f64, smooth: bool) -> Result<Surface<'static>, String> {
+        let source = self.convert_format(pixels::PixelFormatEnum::RGBA32)?;
+        let (width, height) = (source.width() as usize, source.height() as usize);
+
+        let (sin, cos) = degrees.to_radians().sin_cos();
+        // Avoid growing the surface by a pixel because of rounding errors, e.g. at 90 degrees.
+        let bounding = |extent: f64| (extent - 1e-9).ceil().max(1.0) as u32;
+        let new_width = bounding(width as f64 * cos.abs() + height as f64 * sin.abs());
+        let new_height = bounding(width as f64 * sin.abs() + height as f64 * cos.abs());
+        let mut rotated = Surface::new(new_width, new_height, pixels::PixelFormatEnum::RGBA32)?;
+
+        let src_pitch = source.pitch() as usize;
+        let dst_pitch = rotated.pitch() as usize;
+        let (src_cx, src_cy) = (width as f64 / 2.0, height as f64 / 2.0);
+        let (dst_cx, dst_cy) = (new_width as f64 / 2.0, new_height as f64 / 2.0);
+
+        source.with_lock(|src| {
+            // Transparent outside of the source surface.
+            let texel = |x: isize, y: isize| -> [f64; 4] {
+                if x < 0 || y < 0 || x as usize >= width || y as usize >= height {
+                    return [0.0; 4];
+                }
+                let offset = y as usize * src_pitch + x as usize * 4;
+                let p = &src[offset..offset + 4];
+                [p[0] as f64, p[1] as f64, p[2] as f64, p[3] as f64]
+            };
+
+            rotated.with_lock_mut(|dst| {
+                for dy in 0..new_height as usize {
+                    for dx in 0..new_width as usize {
+                        // Map the center of the destination pixel back onto the source.
+                        let x = dx as f64 + 0.5 - dst_cx;
+                        let y = dy as f64 + 0.5 - dst_cy;
+                        let sx = cos * x + sin * y + src_cx;
+                        let sy = -sin * x + cos * y + src_cy;
+
+                        let color = if smooth {
+                            let (u, v) = (sx - 0.5, sy - 0.5);
+                            let (x0, y0) = (u.floor(), v.floor());
+                            let (fx, fy) = (u - x0, v - y0);
+                            let (x0, y0) = (x0 as isize, y0 as isize);
+                            let samples = [
+                                (texel(x0, y0), (1.0 - fx) * (1.0 - fy)),
+                                (texel(x0 + 1, y0), fx * (1.0 - fy)),
+                                (texel(x0, y0 + 1), (1.0 - fx) * fy),
+                                (texel(x0 + 1, y0 + 1), fx * fy),
+                            ];
+                            // Weight the colors by alpha so transparent texels don't darken
+                            // the edges.
+                            let mut rgb = [0.0; 3];
+                            let mut alpha = 0.0;
+                            for (texel, weight) in samples.iter() {
+                                let w = weight * texel[3];
+                                for (c, t) in rgb.iter_mut().zip(texel.iter()) {
+                                    *c += t * w;
+                                }
+                                alpha += w;
+                            }
+                            if alpha > 0.0 {
+                                [rgb[0] / alpha, rgb[1] / alpha, rgb[2] / alpha, alpha]
+                            } else {
+                                [0.0; 4]
+                            }
+                        } else {
+                            texel(sx.floor() as isize, sy.floor() as isize)
+                        };
+
+                        let offset = dy * dst_pitch + dx * 4;
+                        for (d, c) in dst[offset..offset + 4].iter_mut().zip(color.iter()) {
+                            *d = c.round().clamp(0.0, 255.0) as u8;
+                        }
+                    }
+                }
+            })
+        });
+
+        Ok(rotated)
+    }
+
+    /// Locks the surface and returns an iterator over its pixels, yielding `(x, y, color)` row
+    /// by row. The surface is unlocked when the iterator is dropped.
+    ///
+    /// Returns an error if the surface can't be locked, or if its format packs several pixels
+    /// per byte.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use sdl2::pixels::Color;
+    /// use sdl2::surface::Surface;
+    ///
+    /// fn count_transparent(surface: &Surface) -> usize {
+    ///     surface
+    ///         .pixels()
+    ///         .unwrap()
+    ///         .filter(|&(_, _, color)| color.a == 0)
+    ///         .count()
+    /// }
+    /// ```
+    #[doc(alias = "SDL_LockSurface")]
+    pub fn pixels(&self) -> Result<PixelIter<'_>, String> {
+        let bytes_per_pixel = self.bytes_per_pixel()?;
+        unsafe {
+            if sys::SDL_LockSurface(self.raw()) != 0 {
+                return Err(get_error());
+            }
+
+            let raw_pixels = self.raw_ref().pixels as *const u8;
+            let len = self.raw_ref().pitch as usize * (self.raw_ref().h as usize);
+            Ok(PixelIter {
+                surface: self,
+                pixels: ::std::slice::from_raw_parts(raw_pixels, len),
+                bytes_per_pixel,
+                x: 0,
+                y: 0,
+            })
+        }
+    }
+
     #[doc(alias = "SDL_SetSurfacePalette")]
     pub fn set_palette(&mut self, palette: &pixels::Palette) -> Result<(), String> {
         let result = unsafe { sys::SDL_SetSurfacePalette(self.raw(), palette.raw()) };
@@ -487,6 +1335,21 @@ impl SurfaceRef {
         }
     }
 
+    /// Sets the RLE acceleration hint for the surface.
+    ///
+    /// RLE acceleration can substantially speed up blitting of color-keyed surfaces with large
+    /// transparent areas. Once enabled, the surface must be locked before its pixels can be
+    /// accessed directly, using `with_lock` or `with_lock_mut` (see `must_lock`).
+    #[doc(alias = "SDL_SetSurfaceRLE")]
+    pub fn set_rle(&mut self, enable: bool) -> Result<(), String> {
+        let result = unsafe { sys::SDL_SetSurfaceRLE(self.raw(), if enable { 1 } else { 0 }) };
+
+        match result {
+            0 => Ok(()),
+            _ => Err(get_error()),
+        }
+    }
+
     #[doc(alias = "SDL_SetColorKey")]
     pub fn set_color_key(&mut self, enable: bool, color: pixels::Color) -> Result<(), String> {
         let key = color.to_u32(&self.pixel_format());
@@ -616,9 +1479,13 @@ impl SurfaceRef {
         }
     }
 
-    /// Sets the clip rectangle for the surface.
+    /// Sets the clip rectangle for the surface. Blits to the surface and `fill_rect` only affect
+    /// the area inside of the clip rectangle.
     ///
     /// If the rectangle is `None`, clipping will be disabled.
+    ///
+    /// Returns false if the rectangle doesn't intersect the surface, in which case nothing will
+    /// be drawn to it until the clip rectangle is changed.
     #[doc(alias = "SDL_SetClipRect")]
     pub fn set_clip_rect<R>(&mut self, rect: R) -> bool
     where
@@ -681,6 +1548,58 @@ impl SurfaceRef {
         }
     }
 
+    /// Checks the conditions known to make `blit()` from this surface to `dst` fail, and returns
+    /// the specific mismatch instead of SDL's "Blit combination not supported".
+    ///
+    /// This covers locked surfaces, YUV formats, destinations with less than 8 bits per pixel,
+    /// and the blend modes and color modulation that SDL can't apply when either surface has an
+    /// indexed format. An `Ok` doesn't guarantee that the blit succeeds.
+    ///
+    /// Sizes and color keys aren't checked, since they can't make a blit fail: `blit()` clips
+    /// the source and destination rectangles to both surfaces, and every blitter SDL picks for a
+    /// pair of formats supports color keys. The blend mode and color modulation are what make
+    /// SDL find no blitter.
+    pub fn assert_blittable_to(&self, dst: &SurfaceRef) -> Result<(), BlitError> {
+        use self::BlitError::*;
+
+        let (src_raw, dst_raw) = (self.raw_ref(), dst.raw_ref());
+        if src_raw.locked > 0 {
+            return Err(SourceLocked);
+        }
+        if dst_raw.locked > 0 {
+            return Err(DestinationLocked);
+        }
+
+        let (src_format, dst_format) =
+            unsafe { ((*src_raw.format).format, (*dst_raw.format).format) };
+        if is_fourcc_format(src_format) {
+            return Err(UnsupportedSourceFormat(self.pixel_format_enum()));
+        }
+        if is_fourcc_format(dst_format) || unsafe { (*dst_raw.format).BitsPerPixel } < 8 {
+            return Err(UnsupportedDestinationFormat(dst.pixel_format_enum()));
+        }
+
+        if is_indexed_format(src_format) || is_indexed_format(dst_format) {
+            let blend_mode = self.blend_mode();
+            if matches!(blend_mode, BlendMode::Add | BlendMode::Mod | BlendMode::Mul) {
+                return Err(UnsupportedBlendMode {
+                    blend_mode,
+                    src_format: self.pixel_format_enum(),
+                    dst_format: dst.pixel_format_enum(),
+                });
+            }
+            let color_mod = self.color_mod();
+            if color_mod.rgb() != (255, 255, 255) {
+                return Err(UnsupportedColorMod {
+                    color_mod,
+                    src_format: self.pixel_format_enum(),
+                    dst_format: dst.pixel_format_enum(),
+                });
+            }
+        }
+        Ok(())
+    }
+
     /// Performs surface blitting (surface copying).
     ///
     /// Returns the final blit rectangle, if a `dst_rect` was provided.
@@ -718,6 +1637,29 @@ impl SurfaceRef {
         }
     }
 
+    /// Performs surface blitting like `blit()`, using `mode` as the blend mode of this surface for
+    /// the duration of the blit only.
+    ///
+    /// The previous blend mode is restored afterwards, even if the blit fails.
+    pub fn blit_with_blend<R1, R2>(
+        &mut self,
+        src_rect: R1,
+        dst: &mut SurfaceRef,
+        dst_rect: R2,
+        mode: BlendMode,
+    ) -> Result<Option<Rect>, String>
+    where
+        R1: Into<Option<Rect>>,
+        R2: Into<Option<Rect>>,
+    {
+        let old_mode = self.blend_mode();
+        self.set_blend_mode(mode)?;
+        let result = self.blit(src_rect, dst, dst_rect);
+        let restored = self.set_blend_mode(old_mode);
+        let rect = result?;
+        restored.map(|()| rect)
+    }
+
     /// Performs low-level surface blitting.
     ///
     /// Unless you know what you're doing, use `blit()` instead, which will clip the input rectangles.
@@ -1,7 +1,9 @@
 use crate::sys;
 use libc::c_void;
+use std::cell::RefCell;
 use std::marker::PhantomData;
 use std::mem;
+use std::time::Duration;
 
 use crate::TimerSubsystem;
 
@@ -40,6 +42,9 @@ impl TimerSubsystem {
     ///
     /// This function is not recommended in upstream SDL2 as of 2.0.18 and internally
     /// calls the 64-bit variant and masks the result.
+    ///
+    /// The returned value wraps around after about 49 days, so prefer `ticks64()` when computing
+    /// time deltas in long-running programs.
     #[doc(alias = "SDL_GetTicks")]
     pub fn ticks(&self) -> u32 {
         // This is thread-safe as long as the ticks subsystem is inited, and
@@ -129,6 +134,155 @@ extern "C" fn c_timer_callback(_interval: u32, param: *mut c_void) -> u32 {
     unsafe { (*f)() }
 }
 
+/// Rolling statistics over the durations of the last frames, measured with the performance
+/// counter.
+///
+/// Call `frame()` once per frame; the first call only starts the measurement. Only the last
+/// `capacity` durations are kept, and nothing is allocated after construction.
+///
+/// # Example
+/// ```no_run
+/// # fn run(sdl_context: sdl2::Sdl) -> Result<(), String> {
+/// let timer = sdl_context.timer()?;
+/// let mut stats = sdl2::timer::FrameStats::new(&timer, 120);
+/// loop {
+///     stats.frame();
+///     if let (Some(fps), Some(p99)) = (stats.fps(), stats.p99()) {
+///         println!("{:.1} fps, 99th percentile {:?}", fps, p99);
+///     }
+///     // ... draw the frame ...
+/// }
+/// # }
+/// ```
+pub struct FrameStats {
+    timer: TimerSubsystem,
+    frequency: u64,
+    last_counter: Option<u64>,
+    durations: Box<[Duration]>,
+    // Scratch space for computing percentiles without allocating.
+    sorted: RefCell<Box<[Duration]>>,
+    len: usize,
+    next: usize,
+}
+
+impl FrameStats {
+    /// Creates an empty collector keeping the last `capacity` frame durations.
+    ///
+    /// # Panics
+    /// Panics if `capacity` is 0.
+    pub fn new(timer: &TimerSubsystem, capacity: usize) -> FrameStats {
+        assert!(capacity > 0, "FrameStats capacity must be at least 1");
+        FrameStats {
+            timer: timer.clone(),
+            frequency: timer.performance_frequency(),
+            last_counter: None,
+            durations: vec![Duration::ZERO; capacity].into_boxed_slice(),
+            sorted: RefCell::new(vec![Duration::ZERO; capacity].into_boxed_slice()),
+            len: 0,
+            next: 0,
+        }
+    }
+
+    /// Marks the start of a new frame, recording the time elapsed since the previous call.
+    ///
+    /// Returns the recorded duration, or `None` on the first call.
+    pub fn frame(&mut self) -> Option<Duration> {
+        let counter = self.timer.performance_counter();
+        let elapsed = self.last_counter.map(|last| {
+            let ticks = counter.wrapping_sub(last) as u128;
+            Duration::from_nanos((ticks * 1_000_000_000 / self.frequency as u128) as u64)
+        });
+        self.last_counter = Some(counter);
+        if let Some(elapsed) = elapsed {
+            self.record(elapsed);
+        }
+        elapsed
+    }
+
+    /// Records a frame duration measured elsewhere, replacing the oldest one if the buffer is
+    /// full.
+    pub fn record(&mut self, duration: Duration) {
+        self.durations[self.next] = duration;
+        self.next = (self.next + 1) % self.durations.len();
+        self.len = (self.len + 1).min(self.durations.len());
+    }
+
+    /// Drops the recorded durations, and restarts the measurement on the next `frame()` call.
+    pub fn reset(&mut self) {
+        self.last_counter = None;
+        self.len = 0;
+        self.next = 0;
+    }
+
+    /// Returns the maximum number of durations kept.
+    pub fn capacity(&self) -> usize {
+        self.durations.len()
+    }
+
+    /// Returns the number of durations recorded, up to `capacity()`.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if no durations are recorded.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the recorded durations, in no particular order.
+    fn recorded(&self) -> &[Duration] {
+        &self.durations[..self.len]
+    }
+
+    /// Returns the mean frame duration, or `None` if no frames are recorded.
+    pub fn avg(&self) -> Option<Duration> {
+        if self.is_empty() {
+            return None;
+        }
+        let total: Duration = self.recorded().iter().sum();
+        Some(total / self.len as u32)
+    }
+
+    /// Returns the shortest recorded frame duration.
+    pub fn min(&self) -> Option<Duration> {
+        self.recorded().iter().min().copied()
+    }
+
+    /// Returns the longest recorded frame duration.
+    pub fn max(&self) -> Option<Duration> {
+        self.recorded().iter().max().copied()
+    }
+
+    /// Returns the frame duration that `percentile` percent of the recorded frames don't exceed,
+    /// using the nearest-rank method. `percentile` is clamped to 0..=100.
+    pub fn percentile(&self, percentile: f64) -> Option<Duration> {
+        if self.is_empty() {
+            return None;
+        }
+        let percentile = percentile.clamp(0.0, 100.0);
+        let rank = (percentile / 100.0 * self.len as f64).ceil() as usize;
+        let index = rank.max(1) - 1;
+
+        let mut sorted = self.sorted.borrow_mut();
+        let sorted = &mut sorted[..self.len];
+        sorted.copy_from_slice(self.recorded());
+        Some(*sorted.select_nth_unstable(index).1)
+    }
+
+    /// Returns the 99th percentile frame duration, a measure of the worst stutters.
+    pub fn p99(&self) -> Option<Duration> {
+        self.percentile(99.0)
+    }
+
+    /// Returns the frame rate for the mean frame duration.
+    pub fn fps(&self) -> Option<f64> {
+        match self.avg() {
+            Some(avg) if avg > Duration::ZERO => Some(1.0 / avg.as_secs_f64()),
+            _ => None,
+        }
+    }
+}
+
 #[cfg(not(target_os = "macos"))]
 #[cfg(test)]
 mod test {
@@ -140,6 +294,7 @@ mod test {
         test_timer_runs_multiple_times();
         test_timer_runs_at_least_once();
         test_timer_can_be_recreated();
+        test_frame_stats();
     }
 
     fn test_timer_runs_multiple_times() {
@@ -221,4 +376,29 @@ mod test {
         let num = local_num.lock().unwrap();
         assert_eq!(*num, 2);
     }
+
+    fn test_frame_stats() {
+        let sdl_context = crate::sdl::init().unwrap();
+        let timer_subsystem = sdl_context.timer().unwrap();
+
+        let mut stats = super::FrameStats::new(&timer_subsystem, 4);
+        assert_eq!(stats.avg(), None);
+        assert_eq!(stats.fps(), None);
+        assert_eq!(stats.frame(), None);
+        assert!(stats.frame().is_some());
+        assert_eq!(stats.len(), 1);
+
+        stats.reset();
+        for ms in &[50, 10, 20, 30, 40] {
+            stats.record(Duration::from_millis(*ms));
+        }
+        // The first duration was overwritten.
+        assert_eq!(stats.len(), 4);
+        assert_eq!(stats.min(), Some(Duration::from_millis(10)));
+        assert_eq!(stats.max(), Some(Duration::from_millis(40)));
+        assert_eq!(stats.avg(), Some(Duration::from_millis(25)));
+        assert_eq!(stats.p99(), Some(Duration::from_millis(40)));
+        assert_eq!(stats.percentile(50.0), Some(Duration::from_millis(20)));
+        assert!((stats.fps().unwrap() - 40.0).abs() < 1e-9);
+    }
 }
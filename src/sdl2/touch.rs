@@ -1,3 +1,4 @@
+use crate::event::Event;
 use crate::sys;
 
 pub type Finger = sys::SDL_Finger;
@@ -28,3 +29,153 @@ pub fn touch_finger(touch: TouchDevice, index: i32) -> Option<Finger> {
         unsafe { Some(*raw) }
     }
 }
+
+/// Returns all the fingers currently down on `touch`, with their normalized position and
+/// pressure, or an empty `Vec` if there are none.
+///
+/// This allows polling every touch point each frame instead of tracking finger events.
+pub fn active_fingers(touch: TouchDevice) -> Vec<Finger> {
+    (0..num_touch_fingers(touch))
+        .filter_map(|index| touch_finger(touch, index))
+        .collect()
+}
+
+/// Integrates `MultiGesture` events into pinch and rotation deltas, e.g. to zoom and rotate a
+/// map with two fingers.
+///
+/// The deltas accumulate until they are queried. When a finger touches or lifts, the centroid
+/// SDL measures from jumps, so the first `MultiGesture` event after the number of fingers
+/// changes is ignored rather than reported as a sudden pinch.
+///
+/// # Example
+/// ```no_run
+/// use sdl2::touch::GestureRecognizer;
+///
+/// # let mut event_pump = sdl2::init().unwrap().event_pump().unwrap();
+/// let mut gesture = GestureRecognizer::new();
+/// let mut zoom = 1.0;
+/// loop {
+///     for event in event_pump.poll_iter() {
+///         gesture.handle_event(&event);
+///     }
+///     zoom *= 1.0 + gesture.scale_delta();
+/// }
+/// ```
+#[derive(Copy, Clone, PartialEq, Debug, Default)]
+pub struct GestureRecognizer {
+    scale: f32,
+    rotation: f32,
+    touch_id: Option<TouchDevice>,
+    num_fingers: u16,
+}
+
+impl GestureRecognizer {
+    pub fn new() -> GestureRecognizer {
+        GestureRecognizer::default()
+    }
+
+    /// Updates the gesture from `MultiGesture` and `FingerUp` events. Other events are ignored.
+    pub fn handle_event(&mut self, event: &Event) {
+        match *event {
+            Event::MultiGesture {
+                touch_id,
+                d_theta,
+                d_dist,
+                num_fingers,
+                ..
+            } => {
+                if self.touch_id == Some(touch_id) && self.num_fingers == num_fingers {
+                    self.scale += d_dist;
+                    self.rotation += d_theta;
+                } else {
+                    self.touch_id = Some(touch_id);
+                    self.num_fingers = num_fingers;
+                }
+            }
+            // No `MultiGesture` event is sent once fewer than two fingers are down.
+            Event::FingerUp { touch_id, .. } if self.touch_id == Some(touch_id) => {
+                self.touch_id = None;
+                self.num_fingers = 0;
+            }
+            _ => {}
+        }
+    }
+
+    /// Returns whether a multi-finger gesture is in progress.
+    pub fn is_active(&self) -> bool {
+        self.touch_id.is_some()
+    }
+
+    /// Returns how much the fingers spread apart since the last call, in normalized touch
+    /// device units, and resets it. The value is negative when pinching.
+    pub fn scale_delta(&mut self) -> f32 {
+        let scale = self.scale;
+        self.scale = 0.0;
+        scale
+    }
+
+    /// Returns how much the fingers rotated since the last call, in radians, and resets it.
+    pub fn rotation_delta(&mut self) -> f32 {
+        let rotation = self.rotation;
+        self.rotation = 0.0;
+        rotation
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::GestureRecognizer;
+    use crate::event::Event;
+
+    fn gesture(num_fingers: u16, d_dist: f32, d_theta: f32) -> Event {
+        Event::MultiGesture {
+            timestamp: 0,
+            touch_id: 1,
+            d_theta,
+            d_dist,
+            x: 0.5,
+            y: 0.5,
+            num_fingers,
+        }
+    }
+
+    fn finger_up() -> Event {
+        Event::FingerUp {
+            timestamp: 0,
+            touch_id: 1,
+            finger_id: 0,
+            x: 0.5,
+            y: 0.5,
+            dx: 0.0,
+            dy: 0.0,
+            pressure: 0.0,
+        }
+    }
+
+    #[test]
+    fn test_gesture_recognizer() {
+        let mut recognizer = GestureRecognizer::new();
+        assert!(!recognizer.is_active());
+
+        // The first event of a gesture only sets its starting point.
+        recognizer.handle_event(&gesture(2, 0.5, 0.5));
+        assert!(recognizer.is_active());
+        recognizer.handle_event(&gesture(2, 0.25, 0.125));
+        recognizer.handle_event(&gesture(2, -0.125, 0.25));
+        assert_eq!(recognizer.scale_delta(), 0.125);
+        assert_eq!(recognizer.rotation_delta(), 0.375);
+        assert_eq!(recognizer.scale_delta(), 0.0);
+        assert_eq!(recognizer.rotation_delta(), 0.0);
+
+        // A third finger restarts the gesture.
+        recognizer.handle_event(&gesture(3, 0.5, 0.5));
+        recognizer.handle_event(&gesture(3, 0.25, 0.0));
+        assert_eq!(recognizer.scale_delta(), 0.25);
+
+        recognizer.handle_event(&finger_up());
+        assert!(!recognizer.is_active());
+        recognizer.handle_event(&gesture(2, 0.5, 0.5));
+        assert_eq!(recognizer.scale_delta(), 0.0);
+        assert_eq!(recognizer.rotation_delta(), 0.0);
+    }
+}
@@ -48,6 +48,22 @@ impl Sdl2TtfContext {
 
     /// Loads a font from the given SDL2 rwops object with the given size in
     /// points.
+    ///
+    /// This allows loading fonts embedded in the binary or stored inside an
+    /// archive, without going through a temporary file. The font keeps the
+    /// rwops alive and frees it when dropped.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use sdl2::rwops::RWops;
+    /// use sdl2::ttf::{Font, Sdl2TtfContext};
+    ///
+    /// // e.g. `include_bytes!("font.ttf")`
+    /// fn load_embedded_font(ttf_context: &Sdl2TtfContext, data: &'static [u8]) -> Font<'_, 'static> {
+    ///     let rwops = RWops::from_bytes(data).unwrap();
+    ///     ttf_context.load_font_from_rwops(rwops, 16).unwrap()
+    /// }
+    /// ```
     pub fn load_font_from_rwops<'ttf, 'r>(
         &'ttf self,
         rwops: RWops<'r>,
@@ -0,0 +1,300 @@
+use super::font::{Font, FontStyle, Hinting};
+use pixels::Color;
+use rect::{Point, Rect};
+use render::{Canvas, RenderTarget, Texture, TextureCreator};
+use std::collections::HashMap;
+#[cfg(feature = "unsafe_textures")]
+use std::marker::PhantomData;
+
+/// Draws text on a `Canvas` in a single call.
+///
+/// This renders the text to a surface, uploads it to a texture, copies the texture and releases
+/// it again, so the text is rasterized on every call. For text that doesn't change from frame to
+/// frame, keep the texture around instead, or use a `CachedTextRenderer`.
+pub trait DrawText {
+    /// Draws `text` with its top-left corner at `dest`, and returns the area drawn.
+    ///
+    /// The text is rendered with `Font::render(text).blended(color)`. `texture_creator` must
+    /// belong to this canvas.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use sdl2::pixels::Color;
+    /// use sdl2::rect::Point;
+    /// use sdl2::render::WindowCanvas;
+    /// use sdl2::ttf::{DrawText, Font};
+    ///
+    /// fn draw_score(canvas: &mut WindowCanvas, font: &Font, score: u32) -> Result<(), String> {
+    ///     let texture_creator = canvas.texture_creator();
+    ///     let text = format!("Score: {}", score);
+    ///     canvas.draw_text(font, &text, Color::WHITE, Point::new(10, 10), &texture_creator)?;
+    ///     Ok(())
+    /// }
+    /// ```
+    fn draw_text<T>(
+        &mut self,
+        font: &Font,
+        text: &str,
+        color: Color,
+        dest: Point,
+        texture_creator: &TextureCreator<T>,
+    ) -> Result<Rect, String>;
+}
+
+impl<RT: RenderTarget> DrawText for Canvas<RT> {
+    fn draw_text<T>(
+        &mut self,
+        font: &Font,
+        text: &str,
+        color: Color,
+        dest: Point,
+        texture_creator: &TextureCreator<T>,
+    ) -> Result<Rect, String> {
+        let surface = font
+            .render(text)
+            .blended(color)
+            .map_err(|e| e.to_string())?;
+        let texture = texture_creator
+            .create_texture_from_surface(&surface)
+            .map_err(|e| e.to_string())?;
+        let rect = Rect::new(dest.x(), dest.y(), surface.width(), surface.height());
+
+        let result = self.copy(&texture, None, rect);
+        #[cfg(feature = "unsafe_textures")]
+        unsafe {
+            texture.destroy();
+        }
+        result.map_err(|e| e.to_string())?;
+        Ok(rect)
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+struct TextKey {
+    font: usize,
+    text: String,
+    color: (u8, u8, u8, u8),
+}
+
+/// The font settings that affect rendering, used to notice when a font is changed.
+#[derive(PartialEq)]
+struct FontState {
+    style: FontStyle,
+    outline_width: u16,
+    hinting: Hinting,
+    kerning: bool,
+    height: i32,
+}
+
+impl FontState {
+    fn new(font: &Font) -> FontState {
+        FontState {
+            style: font.get_style(),
+            outline_width: font.get_outline_width(),
+            hinting: font.get_hinting(),
+            kerning: font.get_kerning(),
+            height: font.height(),
+        }
+    }
+}
+
+struct CachedText<'r> {
+    #[cfg(not(feature = "unsafe_textures"))]
+    texture: Texture<'r>,
+    #[cfg(feature = "unsafe_textures")]
+    texture: Texture,
+    #[cfg(feature = "unsafe_textures")]
+    _marker: PhantomData<&'r ()>,
+    width: u32,
+    height: u32,
+    last_used: u64,
+}
+
+impl<'r> CachedText<'r> {
+    #[cfg(not(feature = "unsafe_textures"))]
+    fn destroy(self) {}
+
+    #[cfg(feature = "unsafe_textures")]
+    fn destroy(self) {
+        // The texture creator outlives the cache, so its renderer is still alive.
+        unsafe { self.texture.destroy() }
+    }
+}
+
+/// Draws text on a `Canvas`, keeping the rendered textures around for the next frames.
+///
+/// Textures are cached by font, text and color. Once `capacity` textures are cached, drawing new
+/// text evicts the least recently drawn one. Changing the style, outline, hinting, kerning or
+/// size of a font invalidates the textures rendered with it.
+///
+/// Fonts are identified by address, so call `invalidate_font` before dropping a font that was
+/// used with the cache, since a new font may be loaded at the same address.
+///
+/// # Example
+/// ```no_run
+/// use sdl2::pixels::Color;
+/// use sdl2::rect::Point;
+/// use sdl2::render::WindowCanvas;
+/// use sdl2::ttf::{CachedTextRenderer, Font};
+///
+/// fn run(mut canvas: WindowCanvas, font: &Font) -> Result<(), String> {
+///     let texture_creator = canvas.texture_creator();
+///     let mut text = CachedTextRenderer::new(&texture_creator, 64);
+///     loop {
+///         canvas.clear();
+///         // Only rasterized on the first frame.
+///         text.draw(&mut canvas, font, "Paused", Color::WHITE, Point::new(10, 10))?;
+///         canvas.present();
+///     }
+/// }
+/// ```
+pub struct CachedTextRenderer<'r, T: 'r> {
+    texture_creator: &'r TextureCreator<T>,
+    capacity: usize,
+    entries: HashMap<TextKey, CachedText<'r>>,
+    fonts: HashMap<usize, FontState>,
+    clock: u64,
+}
+
+impl<'r, T> CachedTextRenderer<'r, T> {
+    /// Creates an empty cache keeping at most `capacity` textures created with
+    /// `texture_creator`.
+    pub fn new(texture_creator: &'r TextureCreator<T>, capacity: usize) -> Self {
+        CachedTextRenderer {
+            texture_creator,
+            capacity: capacity.max(1),
+            entries: HashMap::new(),
+            fonts: HashMap::new(),
+            clock: 0,
+        }
+    }
+
+    /// Draws `text` with its top-left corner at `dest` and returns the area drawn, rendering it
+    /// with `Font::render(text).blended(color)` only if it isn't cached yet.
+    ///
+    /// `canvas` must be the canvas the texture creator belongs to.
+    pub fn draw<RT: RenderTarget>(
+        &mut self,
+        canvas: &mut Canvas<RT>,
+        font: &Font,
+        text: &str,
+        color: Color,
+        dest: Point,
+    ) -> Result<Rect, String> {
+        let font_id = unsafe { font.raw() } as usize;
+        let state = FontState::new(font);
+        if self.fonts.get(&font_id) != Some(&state) {
+            self.remove_font(font_id);
+            self.fonts.insert(font_id, state);
+        }
+
+        self.clock += 1;
+        let key = TextKey {
+            font: font_id,
+            text: text.to_owned(),
+            color: color.rgba(),
+        };
+        if !self.entries.contains_key(&key) {
+            let surface = font
+                .render(text)
+                .blended(color)
+                .map_err(|e| e.to_string())?;
+            let texture = self
+                .texture_creator
+                .create_texture_from_surface(&surface)
+                .map_err(|e| e.to_string())?;
+            if self.entries.len() >= self.capacity {
+                self.evict_least_recently_used();
+            }
+            self.entries.insert(
+                key.clone(),
+                CachedText {
+                    texture,
+                    #[cfg(feature = "unsafe_textures")]
+                    _marker: PhantomData,
+                    width: surface.width(),
+                    height: surface.height(),
+                    last_used: 0,
+                },
+            );
+        }
+
+        let entry = self.entries.get_mut(&key).unwrap();
+        entry.last_used = self.clock;
+        let rect = Rect::new(dest.x(), dest.y(), entry.width, entry.height);
+        canvas
+            .copy(&entry.texture, None, rect)
+            .map_err(|e| e.to_string())?;
+        Ok(rect)
+    }
+
+    /// Returns the maximum number of cached textures.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Sets the maximum number of cached textures, evicting the least recently drawn ones if
+    /// there are more.
+    pub fn set_capacity(&mut self, capacity: usize) {
+        self.capacity = capacity.max(1);
+        while self.entries.len() > self.capacity {
+            self.evict_least_recently_used();
+        }
+    }
+
+    /// Returns the number of cached textures.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if no textures are cached.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Removes the textures rendered with `font`.
+    pub fn invalidate_font(&mut self, font: &Font) {
+        let font_id = unsafe { font.raw() } as usize;
+        self.remove_font(font_id);
+    }
+
+    /// Removes all cached textures.
+    pub fn clear(&mut self) {
+        for (_, entry) in self.entries.drain() {
+            entry.destroy();
+        }
+        self.fonts.clear();
+    }
+
+    fn remove_font(&mut self, font_id: usize) {
+        self.fonts.remove(&font_id);
+        let keys: Vec<_> = self
+            .entries
+            .keys()
+            .filter(|key| key.font == font_id)
+            .cloned()
+            .collect();
+        for key in keys {
+            if let Some(entry) = self.entries.remove(&key) {
+                entry.destroy();
+            }
+        }
+    }
+
+    fn evict_least_recently_used(&mut self) {
+        let oldest = self
+            .entries
+            .iter()
+            .min_by_key(|(_, entry)| entry.last_used)
+            .map(|(key, _)| key.clone());
+        if let Some(entry) = oldest.and_then(|key| self.entries.remove(&key)) {
+            entry.destroy();
+        }
+    }
+}
+
+impl<'r, T> Drop for CachedTextRenderer<'r, T> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
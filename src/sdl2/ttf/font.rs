@@ -4,6 +4,7 @@
 use get_error;
 use pixels::Color;
 use rwops::RWops;
+use std::convert::TryFrom;
 use std::error;
 use std::ffi::NulError;
 use std::ffi::{CStr, CString};
@@ -14,6 +15,8 @@ use std::path::Path;
 use surface::Surface;
 use sys::ttf;
 use sys::SDL_Surface;
+#[cfg(feature = "ttf-sdf")]
+use sys::SDL_bool;
 
 bitflags! {
     /// The styling of a font.
@@ -224,6 +227,12 @@ impl<'f, 'text> PartialRendering<'f, 'text> {
     }
 }
 
+#[cfg(feature = "ttf-sdf")]
+extern "C" {
+    fn TTF_SetFontSDF(font: *mut ttf::TTF_Font, on_off: SDL_bool) -> c_int;
+    fn TTF_GetFontSDF(font: *const ttf::TTF_Font) -> SDL_bool;
+}
+
 /// A loaded TTF font.
 pub struct Font<'ttf_module, 'rwops> {
     raw: *mut ttf::TTF_Font,
@@ -308,7 +317,7 @@ impl<'ttf, 'r> Font<'ttf, 'r> {
     // this can prevent introducing UB until
     // https://github.com/rust-lang/rust-clippy/issues/5953 is fixed
     #[allow(clippy::trivially_copy_pass_by_ref)]
-    unsafe fn raw(&self) -> *mut ttf::TTF_Font {
+    pub(super) unsafe fn raw(&self) -> *mut ttf::TTF_Font {
         self.raw
     }
 
@@ -336,6 +345,33 @@ impl<'ttf, 'r> Font<'ttf, 'r> {
         }
     }
 
+    /// Renders a single glyph in *blended* mode, e.g. to build a glyph atlas.
+    ///
+    /// Unlike `render_char`, the glyph is rendered on its own, without
+    /// kerning or shaping. Returns an error if the font does not provide a
+    /// glyph for `ch`, or if `ch` is outside of the Basic Multilingual Plane,
+    /// which the linked SDL2_TTF API cannot address.
+    pub fn render_glyph<T>(&self, ch: char, color: T) -> Result<Surface<'static>, String>
+    where
+        T: Into<Color>,
+    {
+        let ch = u16::try_from(u32::from(ch)).map_err(|_| {
+            format!(
+                "character {:?} is outside of the Basic Multilingual Plane",
+                ch
+            )
+        })?;
+        if unsafe { ttf::TTF_GlyphIsProvided(self.raw, ch) } == 0 {
+            return Err(format!("font does not provide a glyph for U+{:04X}", ch));
+        }
+        let raw = unsafe { ttf::TTF_RenderGlyph_Blended(self.raw, ch, color.into().into()) };
+        if raw.is_null() {
+            Err(get_error())
+        } else {
+            Ok(unsafe { Surface::from_ll(raw) })
+        }
+    }
+
     /// Returns the width and height of the given text when rendered using this
     /// font.
     pub fn size_of(&self, text: &str) -> FontResult<(u32, u32)> {
@@ -394,11 +430,44 @@ impl<'ttf, 'r> Font<'ttf, 'r> {
         unsafe { ttf::TTF_GetFontOutline(self.raw) as u16 }
     }
 
-    /// Sets the width of the font's outline.
+    /// Sets the width of the font's outline, in pixels. Text rendered
+    /// afterwards is stroked instead of filled; a width of 0 disables the
+    /// outline.
     pub fn set_outline_width(&mut self, width: u16) {
         unsafe { ttf::TTF_SetFontOutline(self.raw, width as c_int) }
     }
 
+    /// Enables or disables signed distance field rendering.
+    ///
+    /// SDF glyphs stay crisp when scaled, but the text they produce is not
+    /// meant to be displayed as-is: it must be drawn with a shader that
+    /// thresholds the distance stored in the alpha channel.
+    ///
+    /// Requires SDL2_ttf 2.0.18 (the `ttf-sdf` feature).
+    #[cfg(feature = "ttf-sdf")]
+    pub fn set_sdf(&mut self, enabled: bool) -> Result<(), String> {
+        let enabled = if enabled {
+            SDL_bool::SDL_TRUE
+        } else {
+            SDL_bool::SDL_FALSE
+        };
+        let result = unsafe { TTF_SetFontSDF(self.raw, enabled) };
+        if result != 0 {
+            Err(get_error())
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Returns whether signed distance field rendering is enabled.
+    ///
+    /// Requires SDL2_ttf 2.0.18 (the `ttf-sdf` feature).
+    #[cfg(feature = "ttf-sdf")]
+    pub fn sdf(&self) -> bool {
+        let sdf = unsafe { TTF_GetFontSDF(self.raw) };
+        sdf == SDL_bool::SDL_TRUE
+    }
+
     /// Returns the font's freetype hints.
     pub fn get_hinting(&self) -> Hinting {
         unsafe {
@@ -21,11 +21,13 @@
 //! ```
 
 mod context;
+mod draw;
 mod font;
 
 pub use self::context::{
     get_linked_version, has_been_initialized, init, InitError, Sdl2TtfContext,
 };
+pub use self::draw::{CachedTextRenderer, DrawText};
 pub use self::font::{
     Font, FontError, FontResult, FontStyle, GlyphMetrics, Hinting, PartialRendering,
 };
@@ -1,13 +1,15 @@
 use libc::{c_char, c_float, c_int, c_uint};
+use std::cell::Cell;
 use std::convert::TryFrom;
 use std::error::Error;
 use std::ffi::{CStr, CString, NulError};
+use std::marker::PhantomData;
 use std::ops::{Deref, DerefMut};
 use std::rc::Rc;
 use std::{fmt, mem, ptr};
 
 use crate::common::{validate_int, IntegerOrSdlError};
-use crate::pixels::PixelFormatEnum;
+use crate::pixels::{Color, PixelFormatEnum};
 use crate::rect::Rect;
 use crate::render::CanvasBuilder;
 use crate::surface::SurfaceRef;
@@ -481,6 +483,104 @@ impl DisplayMode {
     }
 }
 
+bitflags! {
+    /// The state of a window, as returned by `Window::flags`.
+    pub struct WindowFlags: u32 {
+        /// fullscreen window
+        const FULLSCREEN = sys::SDL_WindowFlags::SDL_WINDOW_FULLSCREEN as u32;
+        /// fullscreen window at the desktop resolution; includes `FULLSCREEN`
+        const FULLSCREEN_DESKTOP = sys::SDL_WindowFlags::SDL_WINDOW_FULLSCREEN_DESKTOP as u32;
+        /// window usable with an OpenGL context
+        const OPENGL = sys::SDL_WindowFlags::SDL_WINDOW_OPENGL as u32;
+        /// window is visible
+        const SHOWN = sys::SDL_WindowFlags::SDL_WINDOW_SHOWN as u32;
+        /// window is not visible
+        const HIDDEN = sys::SDL_WindowFlags::SDL_WINDOW_HIDDEN as u32;
+        /// no window decoration
+        const BORDERLESS = sys::SDL_WindowFlags::SDL_WINDOW_BORDERLESS as u32;
+        /// window can be resized
+        const RESIZABLE = sys::SDL_WindowFlags::SDL_WINDOW_RESIZABLE as u32;
+        /// window is minimized
+        const MINIMIZED = sys::SDL_WindowFlags::SDL_WINDOW_MINIMIZED as u32;
+        /// window is maximized
+        const MAXIMIZED = sys::SDL_WindowFlags::SDL_WINDOW_MAXIMIZED as u32;
+        /// window has grabbed mouse input
+        const MOUSE_GRABBED = sys::SDL_WindowFlags::SDL_WINDOW_MOUSE_GRABBED as u32;
+        /// window has input focus
+        const INPUT_FOCUS = sys::SDL_WindowFlags::SDL_WINDOW_INPUT_FOCUS as u32;
+        /// window has mouse focus
+        const MOUSE_FOCUS = sys::SDL_WindowFlags::SDL_WINDOW_MOUSE_FOCUS as u32;
+        /// window not created by SDL
+        const FOREIGN = sys::SDL_WindowFlags::SDL_WINDOW_FOREIGN as u32;
+        /// window should be created in high-DPI mode if supported
+        const ALLOW_HIGHDPI = sys::SDL_WindowFlags::SDL_WINDOW_ALLOW_HIGHDPI as u32;
+        /// window has mouse captured (unrelated to `MOUSE_GRABBED`)
+        const MOUSE_CAPTURE = sys::SDL_WindowFlags::SDL_WINDOW_MOUSE_CAPTURE as u32;
+        /// window should always be above others
+        const ALWAYS_ON_TOP = sys::SDL_WindowFlags::SDL_WINDOW_ALWAYS_ON_TOP as u32;
+        /// window should not be added to the taskbar
+        const SKIP_TASKBAR = sys::SDL_WindowFlags::SDL_WINDOW_SKIP_TASKBAR as u32;
+        /// window should be treated as a utility window
+        const UTILITY = sys::SDL_WindowFlags::SDL_WINDOW_UTILITY as u32;
+        /// window should be treated as a tooltip
+        const TOOLTIP = sys::SDL_WindowFlags::SDL_WINDOW_TOOLTIP as u32;
+        /// window should be treated as a popup menu
+        const POPUP_MENU = sys::SDL_WindowFlags::SDL_WINDOW_POPUP_MENU as u32;
+        /// window has grabbed keyboard input
+        const KEYBOARD_GRABBED = sys::SDL_WindowFlags::SDL_WINDOW_KEYBOARD_GRABBED as u32;
+        /// window usable for a Vulkan surface
+        const VULKAN = sys::SDL_WindowFlags::SDL_WINDOW_VULKAN as u32;
+        /// window usable for a Metal view
+        const METAL = sys::SDL_WindowFlags::SDL_WINDOW_METAL as u32;
+    }
+}
+
+/// How the shape of a shaped window is computed from the surface given to `Window::set_shape`.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum ShapeMode {
+    /// Pixels with a non-zero alpha value are part of the shape.
+    Default,
+    /// Pixels with an alpha value at or above the cutoff are part of the shape.
+    BinarizeAlpha(u8),
+    /// Pixels with an alpha value below the cutoff are part of the shape.
+    ReverseBinarizeAlpha(u8),
+    /// Pixels of any color other than the color key are part of the shape.
+    ColorKey(Color),
+}
+
+impl ShapeMode {
+    fn to_ll(self) -> sys::SDL_WindowShapeMode {
+        use self::sys::WindowShapeMode::*;
+        let (mode, parameters) = match self {
+            ShapeMode::Default => (
+                ShapeModeDefault,
+                sys::SDL_WindowShapeParams {
+                    binarizationCutoff: 1,
+                },
+            ),
+            ShapeMode::BinarizeAlpha(cutoff) => (
+                ShapeModeBinarizeAlpha,
+                sys::SDL_WindowShapeParams {
+                    binarizationCutoff: cutoff,
+                },
+            ),
+            ShapeMode::ReverseBinarizeAlpha(cutoff) => (
+                ShapeModeReverseBinarizeAlpha,
+                sys::SDL_WindowShapeParams {
+                    binarizationCutoff: cutoff,
+                },
+            ),
+            ShapeMode::ColorKey(color) => (
+                ShapeModeColorKey,
+                sys::SDL_WindowShapeParams {
+                    colorKey: color.into(),
+                },
+            ),
+        };
+        sys::SDL_WindowShapeMode { mode, parameters }
+    }
+}
+
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
 pub enum FullscreenType {
     Off = 0,
@@ -504,6 +604,11 @@ impl FullscreenType {
 pub enum WindowPos {
     Undefined,
     Centered,
+    /// Centered on the display with the given index.
+    ///
+    /// `WindowBuilder::build` returns an error if the display doesn't exist, while
+    /// `Window::set_position` centers the window on the primary display instead.
+    CenteredOn(i32),
     Positioned(i32),
 }
 
@@ -513,10 +618,30 @@ impl From<i32> for WindowPos {
     }
 }
 
+/// The state of a window's progress indicator, see `Window::set_progress`.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum ProgressState {
+    /// No progress is shown.
+    None,
+    /// Progress is shown as ongoing, without a value.
+    Indeterminate,
+    /// Progress is shown with the given value, usually in green.
+    Normal,
+    /// Progress is shown with the given value as paused, usually in yellow.
+    Paused,
+    /// Progress is shown with the given value as failed, usually in red.
+    Error,
+}
+
 fn to_ll_windowpos(pos: WindowPos) -> c_int {
     match pos {
         WindowPos::Undefined => sys::SDL_WINDOWPOS_UNDEFINED_MASK as c_int,
         WindowPos::Centered => sys::SDL_WINDOWPOS_CENTERED_MASK as c_int,
+        // SDL reads the display index from the low 16 bits, and uses the primary display if
+        // there is no such display. Negative indices must not overwrite the mask.
+        WindowPos::CenteredOn(display) => {
+            (sys::SDL_WINDOWPOS_CENTERED_MASK as c_int) | (display & 0xffff)
+        }
         WindowPos::Positioned(x) => x as c_int,
     }
 }
@@ -549,12 +674,19 @@ pub struct WindowContext {
     raw: *mut sys::SDL_Window,
     #[allow(dead_code)]
     pub(crate) metal_view: sys::SDL_MetalView,
+    // false for windows looked up with `VideoSubsystem::window_from_id`, which are owned elsewhere
+    owned: bool,
+    // position and size to restore when leaving fullscreen with `toggle_fullscreen_desktop`
+    windowed_bounds: Cell<Option<(i32, i32, u32, u32)>>,
 }
 
 impl Drop for WindowContext {
     #[inline]
     #[doc(alias = "SDL_DestroyWindow")]
     fn drop(&mut self) {
+        if !self.owned {
+            return;
+        }
         unsafe {
             #[cfg(target_os = "macos")]
             if !self.metal_view.is_null() {
@@ -579,16 +711,47 @@ impl WindowContext {
             subsystem: subsystem.clone(),
             raw,
             metal_view,
+            owned: true,
+            windowed_bounds: Cell::new(None),
         }
     }
 }
 
+/// A non-owning reference to a window, returned by `VideoSubsystem::window_from_id`.
+///
+/// It dereferences to a `Window`, but the `SDL_Window` is not destroyed when the `WindowRef`
+/// (or a `Window` cloned from it) is dropped.
+pub struct WindowRef<'a> {
+    window: Window,
+    _subsystem: PhantomData<&'a VideoSubsystem>,
+}
+
+impl<'a> Deref for WindowRef<'a> {
+    type Target = Window;
+
+    #[inline]
+    fn deref(&self) -> &Window {
+        &self.window
+    }
+}
+
+impl<'a> DerefMut for WindowRef<'a> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Window {
+        &mut self.window
+    }
+}
+
 /// Represents a setting for vsync/swap interval.
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
 #[repr(i32)]
 pub enum SwapInterval {
+    /// Updates are presented immediately, without waiting for the vertical retrace.
     Immediate = 0,
+    /// Updates are synchronized with the vertical retrace.
     VSync = 1,
+    /// Adaptive vsync: updates are synchronized with the vertical retrace, unless a frame was
+    /// late, in which case it is presented immediately to avoid stuttering.
     LateSwapTearing = -1,
 }
 
@@ -713,13 +876,58 @@ impl VideoSubsystem {
         WindowBuilder::new(self, title, width, height)
     }
 
+    /// Gets the window with the given id, such as the `window_id` of a window event, without
+    /// taking ownership of it. Returns `None` if there is no such window.
+    ///
+    /// # Safety
+    ///
+    /// The window is owned by another `Window`, which must not be dropped while the returned
+    /// `WindowRef`, or any `Window` cloned from it, is in use.
+    #[doc(alias = "SDL_GetWindowFromID")]
+    pub unsafe fn window_from_id(&self, id: u32) -> Option<WindowRef<'_>> {
+        let raw = sys::SDL_GetWindowFromID(id);
+        if raw.is_null() {
+            None
+        } else {
+            let context = WindowContext {
+                subsystem: self.clone(),
+                raw,
+                metal_view: ptr::null_mut(),
+                owned: false,
+                windowed_bounds: Cell::new(None),
+            };
+            Some(WindowRef {
+                window: context.into(),
+                _subsystem: PhantomData,
+            })
+        }
+    }
+
+    /// Returns the id of the window that currently has input grab, or `None` if no window is
+    /// grabbed.
+    #[doc(alias = "SDL_GetGrabbedWindow")]
+    pub fn grabbed_window(&self) -> Option<u32> {
+        unsafe {
+            let raw = sys::SDL_GetGrabbedWindow();
+            if raw.is_null() {
+                None
+            } else {
+                Some(sys::SDL_GetWindowID(raw))
+            }
+        }
+    }
+
+    /// Returns the name of the video driver in use, such as `"x11"` or `"wayland"`, or
+    /// `"unknown"` if SDL doesn't report one.
     #[doc(alias = "SDL_GetCurrentVideoDriver")]
     pub fn current_video_driver(&self) -> &'static str {
         use std::str;
 
         unsafe {
             let buf = sys::SDL_GetCurrentVideoDriver();
-            assert!(!buf.is_null());
+            if buf.is_null() {
+                return "unknown";
+            }
 
             str::from_utf8(CStr::from_ptr(buf as *const _).to_bytes()).unwrap()
         }
@@ -879,16 +1087,22 @@ impl VideoSubsystem {
         Orientation::from_ll(unsafe { sys::SDL_GetDisplayOrientation(display_index as c_int) })
     }
 
+    /// Returns `true` if the system screensaver is allowed to run.
+    ///
+    /// SDL disables the screensaver when the video subsystem is initialized, unless the
+    /// `SDL_VIDEO_ALLOW_SCREENSAVER` hint is set.
     #[doc(alias = "SDL_IsScreenSaverEnabled")]
     pub fn is_screen_saver_enabled(&self) -> bool {
         unsafe { sys::SDL_IsScreenSaverEnabled() == sys::SDL_bool::SDL_TRUE }
     }
 
+    /// Allows the system screensaver to run, e.g. while a media player is paused.
     #[doc(alias = "SDL_EnableScreenSaver")]
     pub fn enable_screen_saver(&self) {
         unsafe { sys::SDL_EnableScreenSaver() }
     }
 
+    /// Prevents the system screensaver from running. This is SDL's default.
     #[doc(alias = "SDL_DisableScreenSaver")]
     pub fn disable_screen_saver(&self) {
         unsafe { sys::SDL_DisableScreenSaver() }
@@ -990,6 +1204,21 @@ impl VideoSubsystem {
         }
     }
 
+    /// Sets the swap interval of the current OpenGL context.
+    ///
+    /// Returns an error if the interval isn't supported, which is common for
+    /// `SwapInterval::LateSwapTearing`; a typical fallback is to use `SwapInterval::VSync`.
+    ///
+    /// ```no_run
+    /// # use sdl2::video::SwapInterval;
+    /// # let video_subsystem = sdl2::init().unwrap().video().unwrap();
+    /// if video_subsystem
+    ///     .gl_set_swap_interval(SwapInterval::LateSwapTearing)
+    ///     .is_err()
+    /// {
+    ///     video_subsystem.gl_set_swap_interval(SwapInterval::VSync).unwrap();
+    /// }
+    /// ```
     #[doc(alias = "SDL_GL_SetSwapInterval")]
     pub fn gl_set_swap_interval<S: Into<SwapInterval>>(&self, interval: S) -> Result<(), String> {
         let result = unsafe { sys::SDL_GL_SetSwapInterval(interval.into() as c_int) };
@@ -1000,6 +1229,7 @@ impl VideoSubsystem {
         }
     }
 
+    /// Gets the swap interval of the current OpenGL context.
     #[doc(alias = "SDL_GL_GetSwapInterval")]
     pub fn gl_get_swap_interval(&self) -> SwapInterval {
         unsafe {
@@ -1076,6 +1306,7 @@ pub enum WindowBuildError {
     HeightOverflows(u32),
     WidthOverflows(u32),
     InvalidTitle(NulError),
+    InvalidDisplayIndex(i32),
     SdlError(String),
 }
 
@@ -1087,6 +1318,7 @@ impl fmt::Display for WindowBuildError {
             HeightOverflows(h) => write!(f, "Window height ({}) is too high.", h),
             WidthOverflows(w) => write!(f, "Window width ({}) is too high.", w),
             InvalidTitle(ref e) => write!(f, "Invalid window title: {}", e),
+            InvalidDisplayIndex(d) => write!(f, "Invalid display index: {}", d),
             SdlError(ref e) => write!(f, "SDL error: {}", e),
         }
     }
@@ -1096,7 +1328,10 @@ impl Error for WindowBuildError {
     fn source(&self) -> Option<&(dyn Error + 'static)> {
         match self {
             Self::InvalidTitle(err) => Some(err),
-            Self::HeightOverflows(_) | Self::WidthOverflows(_) | Self::SdlError(_) => None,
+            Self::HeightOverflows(_)
+            | Self::WidthOverflows(_)
+            | Self::InvalidDisplayIndex(_)
+            | Self::SdlError(_) => None,
         }
     }
 }
@@ -1147,6 +1382,14 @@ impl WindowBuilder {
         if self.height >= (1 << 31) {
             return Err(HeightOverflows(self.width));
         }
+        for pos in [self.x, self.y] {
+            if let WindowPos::CenteredOn(display) = pos {
+                let num_displays = unsafe { sys::SDL_GetNumVideoDisplays() };
+                if display < 0 || display >= num_displays {
+                    return Err(InvalidDisplayIndex(display));
+                }
+            }
+        }
 
         let raw_width = self.width as c_int;
         let raw_height = self.height as c_int;
@@ -1211,6 +1454,16 @@ impl WindowBuilder {
         self
     }
 
+    /// Centers the window on the display with index `display_index`.
+    ///
+    /// The index is checked when the window is built, which returns
+    /// `WindowBuildError::InvalidDisplayIndex` if no such display exists.
+    pub fn position_centered_on(&mut self, display_index: i32) -> &mut WindowBuilder {
+        self.x = WindowPos::CenteredOn(display_index);
+        self.y = WindowPos::CenteredOn(display_index);
+        self
+    }
+
     /// Sets the window to fullscreen.
     pub fn fullscreen(&mut self) -> &mut WindowBuilder {
         self.window_flags |= sys::SDL_WindowFlags::SDL_WINDOW_FULLSCREEN as u32;
@@ -1292,6 +1545,8 @@ impl WindowBuilder {
     }
 
     /// Sets shaped state, to create via SDL_CreateShapedWindow instead of SDL_CreateWindow
+    ///
+    /// This is required to use `Window::set_shape` on the created window.
     pub fn set_shaped(&mut self) -> &mut WindowBuilder {
         self.shaped = true;
         self
@@ -1501,12 +1756,20 @@ impl Window {
         }
     }
 
+    /// Get the pixel format associated with the window.
+    ///
+    /// Useful to create textures or surfaces matching the native format of the window, so that
+    /// no conversion is needed when presenting them. Returns `PixelFormatEnum::Unknown` if SDL
+    /// reports a format this crate doesn't know about, or if the query failed.
+    #[doc(alias = "SDL_GetWindowPixelFormat")]
+    pub fn pixel_format(&self) -> PixelFormatEnum {
+        let format = unsafe { sys::SDL_GetWindowPixelFormat(self.context.raw) };
+        PixelFormatEnum::try_from(format).unwrap_or(PixelFormatEnum::Unknown)
+    }
+
     #[doc(alias = "SDL_GetWindowPixelFormat")]
     pub fn window_pixel_format(&self) -> PixelFormatEnum {
-        unsafe {
-            PixelFormatEnum::try_from(sys::SDL_GetWindowPixelFormat(self.context.raw) as u32)
-                .unwrap()
-        }
+        self.pixel_format()
     }
 
     #[doc(alias = "SDL_GetWindowFlags")]
@@ -1514,7 +1777,37 @@ impl Window {
         unsafe { sys::SDL_GetWindowFlags(self.context.raw) }
     }
 
+    /// Returns the current state of the window.
+    #[doc(alias = "SDL_GetWindowFlags")]
+    pub fn flags(&self) -> WindowFlags {
+        WindowFlags::from_bits_truncate(self.window_flags())
+    }
+
+    /// Shows the progress of a long operation on the window's taskbar button.
+    ///
+    /// `value` is the fraction completed, between 0.0 and 1.0. It is ignored for
+    /// `ProgressState::None` and `ProgressState::Indeterminate`.
+    ///
+    /// SDL2 doesn't wrap this, so it is only implemented on Windows, with `ITaskbarList3`. On
+    /// other platforms this does nothing and returns an error saying that it is unsupported,
+    /// which callers can usually ignore.
+    pub fn set_progress(&mut self, state: ProgressState, value: f32) -> Result<(), String> {
+        #[cfg(target_os = "windows")]
+        {
+            taskbar::set_progress(self.context.raw, state, value)
+        }
+        #[cfg(not(target_os = "windows"))]
+        {
+            let _ = (state, value);
+            Err("Window::set_progress is only supported on Windows".to_owned())
+        }
+    }
+
     /// Does the window have input focus?
+    ///
+    /// The window flags are read on each call, so the result is always current. This allows
+    /// e.g. pausing the game or throttling rendering while the window is in the background,
+    /// without tracking `FocusGained`/`FocusLost` window events.
     pub fn has_input_focus(&self) -> bool {
         0 != self.window_flags() & sys::SDL_WindowFlags::SDL_WINDOW_INPUT_FOCUS as u32
     }
@@ -1524,7 +1817,9 @@ impl Window {
         0 != self.window_flags() & sys::SDL_WindowFlags::SDL_WINDOW_INPUT_GRABBED as u32
     }
 
-    /// Does the window have mouse focus?
+    /// Does the window have mouse focus, i.e. is the mouse over it?
+    ///
+    /// Like `has_input_focus`, the window flags are read on each call.
     pub fn has_mouse_focus(&self) -> bool {
         0 != self.window_flags() & sys::SDL_WindowFlags::SDL_WINDOW_MOUSE_FOCUS as u32
     }
@@ -1565,6 +1860,31 @@ impl Window {
         }
     }
 
+    /// Sets the shape of the window from `shape`, a surface of the same size as the window,
+    /// interpreted according to `mode`.
+    ///
+    /// The window must have been created as a shaped window with `WindowBuilder::set_shaped`;
+    /// otherwise this returns an error.
+    #[doc(alias = "SDL_SetWindowShape")]
+    pub fn set_shape<S: AsRef<SurfaceRef>>(
+        &mut self,
+        shape: S,
+        mode: ShapeMode,
+    ) -> Result<(), String> {
+        let mut shape_mode = mode.to_ll();
+        let result = unsafe {
+            sys::SDL_SetWindowShape(self.context.raw, shape.as_ref().raw(), &mut shape_mode)
+        };
+        match result {
+            0 => Ok(()),
+            sys::SDL_NONSHAPEABLE_WINDOW => Err("the window is not a shaped window".to_owned()),
+            sys::SDL_INVALID_SHAPE_ARGUMENT => {
+                Err("the shape surface does not match the window".to_owned())
+            }
+            _ => Err(get_error()),
+        }
+    }
+
     /// Set the shape of the window
     /// To be effective:
     /// - shaped must have been set using windows builder
@@ -1694,6 +2014,20 @@ impl Window {
         (w as u32, h as u32)
     }
 
+    /// Returns the size of the window's client area in pixels, which may be larger than
+    /// [`size`](Self::size) on high-DPI displays.
+    ///
+    /// This is the framebuffer size to use for both OpenGL and renderer output. It requires
+    /// SDL 2.26.0; with an older SDL, use `Canvas::output_size`, `drawable_size` or
+    /// `vulkan_drawable_size` instead.
+    #[doc(alias = "SDL_GetWindowSizeInPixels")]
+    pub fn size_in_pixels(&self) -> (u32, u32) {
+        let mut w: c_int = 0;
+        let mut h: c_int = 0;
+        unsafe { sys::SDL_GetWindowSizeInPixels(self.context.raw, &mut w, &mut h) };
+        (w as u32, h as u32)
+    }
+
     #[doc(alias = "SDL_SetWindowMinimumSize")]
     pub fn set_minimum_size(&mut self, width: u32, height: u32) -> Result<(), IntegerOrSdlError> {
         let w = validate_int(width, "width")?;
@@ -1759,6 +2093,21 @@ impl Window {
         unsafe { sys::SDL_RaiseWindow(self.context.raw) }
     }
 
+    /// Explicitly sets input focus to the window.
+    ///
+    /// The window must be visible. Prefer `raise`, which also brings the window to the front;
+    /// this method may have unexpected effects on some platforms, and returns an error on
+    /// platforms where it isn't supported.
+    #[doc(alias = "SDL_SetWindowInputFocus")]
+    pub fn set_input_focus(&mut self) -> Result<(), String> {
+        let result = unsafe { sys::SDL_SetWindowInputFocus(self.context.raw) };
+        if result < 0 {
+            Err(get_error())
+        } else {
+            Ok(())
+        }
+    }
+
     #[doc(alias = "SDL_MaximizeWindow")]
     pub fn maximize(&mut self) {
         unsafe { sys::SDL_MaximizeWindow(self.context.raw) }
@@ -1790,6 +2139,31 @@ impl Window {
         }
     }
 
+    /// Switches between desktop fullscreen and windowed mode, returning whether the window is now
+    /// fullscreen.
+    ///
+    /// The position and size of the window are saved when entering fullscreen, and restored when
+    /// leaving it. A window in exclusive (`FullscreenType::True`) fullscreen is switched back to
+    /// windowed mode.
+    pub fn toggle_fullscreen_desktop(&mut self) -> Result<bool, String> {
+        if self.fullscreen_state() == FullscreenType::Off {
+            let (x, y) = self.position();
+            let (width, height) = self.size();
+            self.set_fullscreen(FullscreenType::Desktop)?;
+            self.context
+                .windowed_bounds
+                .set(Some((x, y, width, height)));
+            Ok(true)
+        } else {
+            self.set_fullscreen(FullscreenType::Off)?;
+            if let Some((x, y, width, height)) = self.context.windowed_bounds.take() {
+                self.set_size(width, height).map_err(|e| e.to_string())?;
+                self.set_position(WindowPos::Positioned(x), WindowPos::Positioned(y));
+            }
+            Ok(false)
+        }
+    }
+
     /// Returns a WindowSurfaceRef, which can be used like a regular Surface. This is an
     /// alternative way to the Renderer (Canvas) way to modify pixels directly in the Window.
     ///
@@ -2138,3 +2512,219 @@ pub fn drivers() -> DriverIterator {
         index: 0,
     }
 }
+
+/// Sets an environment variable read by SDL, rejecting values `std::env::set_var` would panic on.
+fn set_sdl_env_var(name: &str, value: &str) -> Result<(), String> {
+    if value.is_empty() || value.contains('\0') {
+        return Err(format!("invalid value {:?} for {}", value, name));
+    }
+    std::env::set_var(name, value);
+    Ok(())
+}
+
+/// Sets the application ID that Wayland compositors use to group the windows of the program and
+/// to find its icon and `.desktop` file, e.g. `"com.example.mygame"`. It should match the name
+/// of the `.desktop` file, without the extension. By default SDL uses the executable name.
+///
+/// SDL2 reads the ID from the `SDL_VIDEO_WAYLAND_WMCLASS` environment variable, which this sets,
+/// when the video subsystem is initialized: this must be called before `Sdl::video()` to have
+/// an effect. It is ignored on other platforms.
+///
+/// # Example
+/// ```no_run
+/// sdl2::video::set_wayland_app_id("com.example.mygame").unwrap();
+/// sdl2::video::set_x11_wm_class("com.example.mygame").unwrap();
+///
+/// let sdl_context = sdl2::init().unwrap();
+/// let video_subsystem = sdl_context.video().unwrap();
+/// ```
+pub fn set_wayland_app_id(id: &str) -> Result<(), String> {
+    set_sdl_env_var("SDL_VIDEO_WAYLAND_WMCLASS", id)
+}
+
+/// Sets the class of the `WM_CLASS` property of X11 windows, which X11 desktops use to group the
+/// windows of the program and to find its icon. By default SDL uses the executable name.
+///
+/// SDL2 reads the class from the `SDL_VIDEO_X11_WMCLASS` environment variable, which this sets,
+/// when the video subsystem is initialized: this must be called before `Sdl::video()` to have
+/// an effect. On Wayland, it is used as the application ID unless `set_wayland_app_id` was
+/// called. It is ignored on other platforms.
+pub fn set_x11_wm_class(class: &str) -> Result<(), String> {
+    set_sdl_env_var("SDL_VIDEO_X11_WMCLASS", class)
+}
+
+/// Taskbar progress through the `ITaskbarList3` COM interface, which SDL2 doesn't wrap.
+#[cfg(target_os = "windows")]
+mod taskbar {
+    use super::ProgressState;
+    use crate::sys;
+    use libc::{c_int, c_void};
+    use std::ptr;
+
+    #[repr(C)]
+    struct Guid {
+        data1: u32,
+        data2: u16,
+        data3: u16,
+        data4: [u8; 8],
+    }
+
+    // {56FDF344-FD6D-11d0-958A-006097C9A090}
+    const CLSID_TASKBAR_LIST: Guid = Guid {
+        data1: 0x56fd_f344,
+        data2: 0xfd6d,
+        data3: 0x11d0,
+        data4: [0x95, 0x8a, 0x00, 0x60, 0x97, 0xc9, 0xa0, 0x90],
+    };
+
+    // {EA1AFB91-9E28-4B86-90E9-9E9F8A5EEFAF}
+    const IID_ITASKBAR_LIST3: Guid = Guid {
+        data1: 0xea1a_fb91,
+        data2: 0x9e28,
+        data3: 0x4b86,
+        data4: [0x90, 0xe9, 0x9e, 0x9f, 0x8a, 0x5e, 0xef, 0xaf],
+    };
+
+    const COINIT_APARTMENTTHREADED: u32 = 0x2;
+    const CLSCTX_INPROC_SERVER: u32 = 0x1;
+    const RPC_E_CHANGED_MODE: i32 = 0x8001_0106_u32 as i32;
+    const SDL_SYSWM_WINDOWS: c_int = 1;
+
+    const TBPF_NOPROGRESS: c_int = 0x0;
+    const TBPF_INDETERMINATE: c_int = 0x1;
+    const TBPF_NORMAL: c_int = 0x2;
+    const TBPF_ERROR: c_int = 0x4;
+    const TBPF_PAUSED: c_int = 0x8;
+
+    type Hresult = i32;
+
+    #[repr(C)]
+    struct TaskbarList3 {
+        vtbl: *const TaskbarList3Vtbl,
+    }
+
+    /// The vtable of `ITaskbarList3`, in declaration order including the inherited
+    /// `IUnknown`, `ITaskbarList` and `ITaskbarList2` methods. Unused entries are not typed.
+    #[repr(C)]
+    struct TaskbarList3Vtbl {
+        query_interface: usize,
+        add_ref: usize,
+        release: unsafe extern "system" fn(*mut TaskbarList3) -> u32,
+        hr_init: unsafe extern "system" fn(*mut TaskbarList3) -> Hresult,
+        add_tab: usize,
+        delete_tab: usize,
+        activate_tab: usize,
+        set_active_alt: usize,
+        mark_fullscreen_window: usize,
+        set_progress_value:
+            unsafe extern "system" fn(*mut TaskbarList3, *mut c_void, u64, u64) -> Hresult,
+        set_progress_state:
+            unsafe extern "system" fn(*mut TaskbarList3, *mut c_void, c_int) -> Hresult,
+    }
+
+    /// `SDL_SysWMinfo` with only the Win32 member of its union.
+    #[repr(C)]
+    struct SysWMinfo {
+        version: sys::SDL_version,
+        subsystem: c_int,
+        window: *mut c_void,
+        hdc: *mut c_void,
+        hinstance: *mut c_void,
+        _dummy: [u8; 64 - 3 * std::mem::size_of::<*mut c_void>()],
+    }
+
+    #[link(name = "ole32")]
+    extern "system" {
+        fn CoInitializeEx(reserved: *mut c_void, coinit: u32) -> Hresult;
+        fn CoUninitialize();
+        fn CoCreateInstance(
+            clsid: *const Guid,
+            outer: *mut c_void,
+            context: u32,
+            iid: *const Guid,
+            object: *mut *mut c_void,
+        ) -> Hresult;
+    }
+
+    extern "C" {
+        fn SDL_GetWindowWMInfo(window: *mut sys::SDL_Window, info: *mut SysWMinfo)
+            -> sys::SDL_bool;
+    }
+
+    fn check(result: Hresult, what: &str) -> Result<(), String> {
+        if result < 0 {
+            Err(format!("{} failed with HRESULT {:#010x}", what, result))
+        } else {
+            Ok(())
+        }
+    }
+
+    pub fn set_progress(
+        window: *mut sys::SDL_Window,
+        state: ProgressState,
+        value: f32,
+    ) -> Result<(), String> {
+        let hwnd = unsafe {
+            let mut info: SysWMinfo = std::mem::zeroed();
+            sys::SDL_GetVersion(&mut info.version);
+            if SDL_GetWindowWMInfo(window, &mut info) == sys::SDL_bool::SDL_FALSE {
+                return Err(crate::get_error());
+            }
+            if info.subsystem != SDL_SYSWM_WINDOWS {
+                return Err("Window::set_progress needs a Win32 window".to_owned());
+            }
+            info.window
+        };
+
+        let init = unsafe { CoInitializeEx(ptr::null_mut(), COINIT_APARTMENTTHREADED) };
+        // COM already initialized in another mode on this thread is still usable.
+        if init != RPC_E_CHANGED_MODE {
+            check(init, "CoInitializeEx")?;
+        }
+
+        let result = unsafe {
+            let mut taskbar: *mut c_void = ptr::null_mut();
+            let created = CoCreateInstance(
+                &CLSID_TASKBAR_LIST,
+                ptr::null_mut(),
+                CLSCTX_INPROC_SERVER,
+                &IID_ITASKBAR_LIST3,
+                &mut taskbar,
+            );
+            check(created, "CoCreateInstance(TaskbarList)").and_then(|()| {
+                let taskbar = taskbar as *mut TaskbarList3;
+                let vtbl = &*(*taskbar).vtbl;
+                let result =
+                    check((vtbl.hr_init)(taskbar), "ITaskbarList::HrInit").and_then(|()| {
+                        let flags = match state {
+                            ProgressState::None => TBPF_NOPROGRESS,
+                            ProgressState::Indeterminate => TBPF_INDETERMINATE,
+                            ProgressState::Normal => TBPF_NORMAL,
+                            ProgressState::Paused => TBPF_PAUSED,
+                            ProgressState::Error => TBPF_ERROR,
+                        };
+                        check(
+                            (vtbl.set_progress_state)(taskbar, hwnd, flags),
+                            "ITaskbarList3::SetProgressState",
+                        )?;
+                        if flags & (TBPF_NORMAL | TBPF_PAUSED | TBPF_ERROR) != 0 {
+                            const TOTAL: u64 = 10_000;
+                            let completed = (value.clamp(0.0, 1.0) * TOTAL as f32) as u64;
+                            check(
+                                (vtbl.set_progress_value)(taskbar, hwnd, completed, TOTAL),
+                                "ITaskbarList3::SetProgressValue",
+                            )?;
+                        }
+                        Ok(())
+                    });
+                (vtbl.release)(taskbar);
+                result
+            })
+        };
+
+        if init >= 0 {
+            unsafe { CoUninitialize() };
+        }
+        result
+    }
+}
@@ -21,6 +21,7 @@ fn test_events() {
 
     test1(&ev);
     test2(&ev, &mut ep);
+    test_inject(&ev, &mut ep);
 
     drop(ev);
     drop(ep);
@@ -85,6 +86,36 @@ fn test2(ev: &sdl2::EventSubsystem, ep: &mut sdl2::EventPump) {
     }
 }
 
+fn test_inject(ev: &sdl2::EventSubsystem, ep: &mut sdl2::EventPump) {
+    let user_event_id = unsafe { ev.register_event().unwrap() };
+    let events: Vec<_> = (0..3)
+        .map(|code| event::Event::User {
+            timestamp: 0,
+            window_id: 0,
+            type_: user_event_id,
+            code,
+            data1: std::ptr::null_mut(),
+            data2: std::ptr::null_mut(),
+        })
+        .collect();
+
+    ev.inject(&events).unwrap();
+    for expected in 0..3 {
+        match ep.poll_event() {
+            Some(event::Event::User { code, .. }) => assert_eq!(code, expected),
+            other => panic!("Received non User event: {:?}", other),
+        }
+    }
+
+    // Nothing is queued if one of the events can't be converted.
+    let unsupported = event::Event::Unknown {
+        timestamp: 0,
+        type_: 0,
+    };
+    assert!(ev.inject(&[events[0].clone(), unsupported]).is_err());
+    assert!(ep.poll_event().is_none());
+}
+
 #[allow(unused)]
 struct SomeEventTypeTest3 {
     a: u32,